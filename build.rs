@@ -0,0 +1,28 @@
+#[cfg(feature = "ffi")]
+fn generate_header() {
+    use std::env;
+    use std::path::PathBuf;
+
+    println!("cargo:rerun-if-changed=src/ffi");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed=GOBBLETS_UPDATE_HEADER");
+
+    let header_path = match env::var_os("GOBBLETS_UPDATE_HEADER") {
+        Some(_) => PathBuf::from("include/gobblets_gobblers.h"),
+        None => PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("gobblets_gobblers.h"),
+    };
+
+    cbindgen::Builder::new()
+        .with_src("src/ffi/ffi_game.rs")
+        .with_config(cbindgen::Config::from_file("cbindgen.toml").unwrap())
+        .generate()
+        .expect("Impossible de générer l'en-tête C")
+        .write_to_file(header_path);
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    #[cfg(feature = "ffi")]
+    generate_header();
+}
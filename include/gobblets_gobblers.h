@@ -0,0 +1,141 @@
+#ifndef GOBBLETS_GOBBLERS_H
+#define GOBBLETS_GOBBLERS_H
+
+/* Fichier généré par cbindgen à la compilation, ne pas modifier. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Une case empile au plus une pièce de chaque taille.
+ */
+#define GOBBLETS_MAX_STACK_HEIGHT 3
+
+typedef enum GobbletsColor {
+  GOBBLETS_COLOR_RED = 0,
+  GOBBLETS_COLOR_BLUE = 1,
+} GobbletsColor;
+
+typedef enum GobbletsError {
+  GOBBLETS_ERROR_OK = 0,
+  GOBBLETS_ERROR_CURRENTLY_NO_GAME = 1,
+  GOBBLETS_ERROR_CURRENTLY_NO_MATCH = 2,
+  GOBBLETS_ERROR_CANNOT_PUT_PIECE_HERE = 3,
+  GOBBLETS_ERROR_SQUARE_IS_EMPTY = 4,
+  GOBBLETS_ERROR_PIECE_NOT_AVAILABLE = 5,
+  GOBBLETS_ERROR_NOT_YOUR_PIECE = 6,
+  GOBBLETS_ERROR_GAME_IS_OVER = 7,
+  GOBBLETS_ERROR_INVALID_POSITION = 8,
+  GOBBLETS_ERROR_UNKNOWN_ERROR = 9,
+  GOBBLETS_ERROR_INVALID_ARGUMENT = 10,
+  GOBBLETS_ERROR_UNSUPPORTED_COMMAND = 11,
+} GobbletsError;
+
+typedef enum GobbletsOutcome {
+  GOBBLETS_OUTCOME_IN_PROGRESS = 0,
+  GOBBLETS_OUTCOME_RED_WINS = 1,
+  GOBBLETS_OUTCOME_BLUE_WINS = 2,
+  GOBBLETS_OUTCOME_DRAW = 3,
+} GobbletsOutcome;
+
+typedef enum GobbletsPieceSize {
+  GOBBLETS_PIECE_SIZE_SMALL = 0,
+  GOBBLETS_PIECE_SIZE_MEDIUM = 1,
+  GOBBLETS_PIECE_SIZE_BIG = 2,
+} GobbletsPieceSize;
+
+/**
+ * Partie manipulée à travers les règles du jeu uniquement : les commandes,
+ * événements, listeners, bots et matchs de `Game` ne sont pas exposés en C.
+ */
+typedef struct GobbletsGame GobbletsGame;
+
+typedef struct GobbletsPiece {
+  enum GobbletsColor color;
+  enum GobbletsPieceSize size;
+} GobbletsPiece;
+
+/**
+ * Pile d'une case, de la pièce du dessous à la pièce visible.
+ */
+typedef struct GobbletsSquare {
+  uint32_t height;
+  struct GobbletsPiece pieces[GOBBLETS_MAX_STACK_HEIGHT];
+} GobbletsSquare;
+
+typedef struct GobbletsPlayer {
+  enum GobbletsColor color;
+  uint32_t small_pieces;
+  uint32_t medium_pieces;
+  uint32_t big_pieces;
+  /**
+   * Temps restant en millisecondes, ou -1 pour une partie sans pendule.
+   */
+  int64_t remaining_time_ms;
+} GobbletsPlayer;
+
+typedef struct GobbletsState {
+  struct GobbletsSquare squares[3][3];
+  struct GobbletsPlayer players[2];
+  uint32_t turn;
+  enum GobbletsColor current_color;
+  enum GobbletsOutcome outcome;
+} GobbletsState;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct GobbletsGame *gobblets_game_new(void);
+
+/**
+ * # Safety
+ *
+ * `position` doit être une chaîne C valide terminée par un octet nul.
+ */
+struct GobbletsGame *gobblets_game_from_position(const char *position);
+
+/**
+ * # Safety
+ *
+ * `game` doit être nul ou provenir de `gobblets_game_new`, et n'être libéré qu'une fois.
+ */
+void gobblets_game_free(struct GobbletsGame *game);
+
+/**
+ * # Safety
+ *
+ * `game` doit être nul ou pointer vers une partie valide.
+ */
+enum GobbletsError gobblets_game_put_piece(struct GobbletsGame *game,
+                                           uint32_t x,
+                                           uint32_t y,
+                                           enum GobbletsPieceSize size);
+
+/**
+ * # Safety
+ *
+ * `game` doit être nul ou pointer vers une partie valide.
+ */
+enum GobbletsError gobblets_game_move_piece(struct GobbletsGame *game,
+                                            uint32_t origin_x,
+                                            uint32_t origin_y,
+                                            uint32_t destination_x,
+                                            uint32_t destination_y);
+
+/**
+ * # Safety
+ *
+ * `game` doit être nul ou pointer vers une partie valide, et `state` être nul ou
+ * pointer vers une zone inscriptible de taille suffisante.
+ */
+enum GobbletsError gobblets_game_get_state(const struct GobbletsGame *game,
+                                           struct GobbletsState *state);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* GOBBLETS_GOBBLERS_H */
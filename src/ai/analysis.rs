@@ -0,0 +1,173 @@
+use crate::ai::solver::Solver;
+use crate::model::analysis_report::{AnalysisReport, AnalyzedMove};
+use crate::model::game_error::GameError;
+use crate::model::game_instance::GameInstance;
+use crate::model::game_move::Move;
+use crate::model::solution::Solution;
+
+const DEFAULT_MAX_POSITIONS: usize = 20_000;
+
+pub struct Analyzer {
+    max_positions: usize,
+}
+
+impl Default for Analyzer {
+    fn default() -> Self {
+        Analyzer::new(DEFAULT_MAX_POSITIONS)
+    }
+}
+
+impl Analyzer {
+    pub fn new(max_positions: usize) -> Analyzer {
+        Analyzer {
+            max_positions: max_positions.max(1),
+        }
+    }
+
+    pub fn analyze(
+        &self,
+        initial_game_instance: &GameInstance,
+        history: &[Move],
+    ) -> Result<AnalysisReport, GameError> {
+        let solver = Solver::new(self.max_positions);
+        let mut game_instance = initial_game_instance.without_clock();
+        let mut best_solution = solver.solve(&game_instance);
+        let mut moves = Vec::with_capacity(history.len());
+
+        for (index, played_move) in history.iter().enumerate() {
+            let color = game_instance.get_current_color();
+
+            game_instance.play(*played_move)?;
+
+            let next_solution = solver.solve(&game_instance);
+
+            moves.push(AnalyzedMove {
+                turn: index as u32 + 1,
+                color,
+                played_move: *played_move,
+                played_solution: next_solution.map(Solution::from_opponent),
+                best_solution,
+            });
+
+            best_solution = next_solution;
+        }
+
+        Ok(AnalysisReport {
+            max_positions: self.max_positions,
+            moves,
+            winner: game_instance.get_winner(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ai::analysis::Analyzer;
+    use crate::model::game_instance::GameInstance;
+    use crate::model::game_move::Move;
+    use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+    use crate::model::player::Color::{Blue, Red};
+    use crate::model::solution::{Outcome, Solution};
+
+    fn blundered_game() -> Vec<Move> {
+        vec![
+            Move::PutPiece(0, 0, Big),
+            Move::PutPiece(2, 0, Big),
+            Move::PutPiece(0, 1, Big),
+            Move::PutPiece(2, 1, Big),
+            Move::PutPiece(1, 1, Medium),
+            Move::PutPiece(2, 2, Small),
+        ]
+    }
+
+    #[test]
+    fn analyze_flags_blunder_test() {
+        let report = Analyzer::new(1_000)
+            .analyze(&GameInstance::default(), &blundered_game())
+            .unwrap();
+
+        assert_eq!(report.moves.len(), 6);
+        assert_eq!(report.winner, Some(Blue));
+
+        let blunders: Vec<_> = report.blunders().collect();
+        assert_eq!(blunders.len(), 1);
+        assert_eq!(blunders[0].turn, 5);
+        assert_eq!(blunders[0].color, Red);
+        assert_eq!(
+            blunders[0].played_solution,
+            Some(Solution {
+                outcome: Outcome::Loss,
+                plies: 2,
+                best_move: None,
+            })
+        );
+        assert!(matches!(
+            blunders[0].best_solution,
+            Some(Solution {
+                outcome: Outcome::Win,
+                plies: 1,
+                best_move: Some(Move::PutPiece(0, 2, _)),
+            })
+        ));
+
+        assert_eq!(report.moves[0].played_solution, None);
+        assert_eq!(
+            report.moves[3]
+                .played_solution
+                .map(|solution| solution.outcome),
+            Some(Outcome::Loss)
+        );
+        assert_eq!(report.moves[3].best_solution, None);
+        assert!(!report.moves[3].is_blunder());
+
+        assert_eq!(
+            report.moves[5]
+                .played_solution
+                .map(|solution| solution.outcome),
+            Some(Outcome::Win)
+        );
+        assert!(!report.moves[5].is_blunder());
+    }
+
+    #[test]
+    fn analyze_illegal_history_error_test() {
+        let history = vec![Move::PutPiece(0, 0, Big), Move::PutPiece(0, 0, Medium)];
+
+        assert!(Analyzer::new(1)
+            .analyze(&GameInstance::default(), &history)
+            .is_err());
+    }
+
+    #[test]
+    fn report_display_test() {
+        let report = Analyzer::new(1_000)
+            .analyze(&GameInstance::default(), &blundered_game())
+            .unwrap();
+
+        let rendered_report = report.to_string();
+
+        assert!(rendered_report.contains("1. Rouge pose une grande en (0, 0) : issue inconnue\n"));
+        assert!(rendered_report.contains("4. Bleu pose une grande en (2, 1) : perdant en 2\n"));
+        assert!(rendered_report.contains(
+            "5. Rouge pose une moyenne en (1, 1) : perdant en 2 ?? gaffe, meilleur coup : pose une petite en (0, 2) (gagnant en 1)\n"
+        ));
+        assert!(rendered_report.contains("Vainqueur : Bleu"));
+        assert!(rendered_report.contains("1 gaffe(s) relevée(s)"));
+    }
+
+    #[test]
+    fn report_export_test() {
+        let report = Analyzer::new(1)
+            .analyze(&GameInstance::default(), &blundered_game())
+            .unwrap();
+        let path = std::env::temp_dir().join(format!(
+            "gobblets_gobblers_analysis_export_test_{}.txt",
+            std::process::id()
+        ));
+
+        report.export(&path).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), report.to_string());
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,72 @@
+use crate::model::evaluation::WIN_SCORE;
+use crate::model::game_instance::GameInstance;
+use crate::model::player::Color;
+
+const LINES: [[(usize, usize); 3]; 8] = [
+    [(0, 0), (0, 1), (0, 2)],
+    [(1, 0), (1, 1), (1, 2)],
+    [(2, 0), (2, 1), (2, 2)],
+    [(0, 0), (1, 0), (2, 0)],
+    [(0, 1), (1, 1), (2, 1)],
+    [(0, 2), (1, 2), (2, 2)],
+    [(0, 0), (1, 1), (2, 2)],
+    [(0, 2), (1, 1), (2, 0)],
+];
+
+pub(crate) fn terminal_score(winner: Color, color: Color, ply: u32) -> i32 {
+    if winner == color {
+        WIN_SCORE - ply as i32
+    } else {
+        -WIN_SCORE + ply as i32
+    }
+}
+
+pub(crate) fn heuristic_score(game_instance: &GameInstance, color: Color) -> i32 {
+    let board = game_instance.get_board();
+    let mut score = 0;
+
+    for line in LINES {
+        let mut own_count = 0;
+        let mut opponent_count = 0;
+
+        for (x, y) in line {
+            match board.get_piece_color(x, y) {
+                Some(piece_color) if piece_color == color => own_count += 1,
+                Some(_) => opponent_count += 1,
+                None => {}
+            }
+        }
+
+        if opponent_count == 0 {
+            score += own_count * own_count;
+        } else if own_count == 0 {
+            score -= opponent_count * opponent_count;
+        }
+    }
+
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ai::evaluation::heuristic_score;
+    use crate::model::game_instance::GameInstance;
+    use crate::model::piece_size::PieceSize::{Big, Small};
+    use crate::model::player::Color::{Blue, Red};
+
+    #[test]
+    fn heuristic_score_test() {
+        let mut game_instance = GameInstance::default();
+
+        assert_eq!(heuristic_score(&game_instance, Red), 0);
+
+        game_instance.put_piece(1, 1, Small).unwrap();
+
+        assert!(heuristic_score(&game_instance, Red) > 0);
+        assert!(heuristic_score(&game_instance, Blue) < 0);
+
+        game_instance.put_piece(1, 1, Big).unwrap();
+
+        assert!(heuristic_score(&game_instance, Blue) > 0);
+    }
+}
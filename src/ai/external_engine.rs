@@ -0,0 +1,288 @@
+use crate::ai::strategy::Strategy;
+use crate::engine::engine_protocol::{
+    parse_engine_response, EngineCommand, EngineResponse, SearchLimits,
+};
+use crate::model::game_instance::GameInstance;
+use crate::model::game_move::Move;
+use std::io;
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc;
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_THINK_TIME: Duration = Duration::from_secs(1);
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+const RESPONSE_MARGIN: Duration = Duration::from_millis(200);
+
+struct EngineProcess {
+    child: Child,
+    stdin: ChildStdin,
+    rx_response: Receiver<EngineResponse>,
+    reader_handle: Option<JoinHandle<()>>,
+}
+
+impl EngineProcess {
+    fn spawn(program: &str, arguments: &[String]) -> io::Result<EngineProcess> {
+        let mut child = Command::new(program)
+            .args(arguments)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        let stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| io::Error::other("Entrée du moteur indisponible"))?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| io::Error::other("Sortie du moteur indisponible"))?;
+        let (tx_response, rx_response) = mpsc::channel();
+
+        let reader_handle = thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let response = match line.map(|line| parse_engine_response(&line)) {
+                    Ok(Ok(response)) => response,
+                    Ok(Err(_)) => continue,
+                    Err(_) => break,
+                };
+                if tx_response.send(response).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut engine_process = EngineProcess {
+            child,
+            stdin,
+            rx_response,
+            reader_handle: Some(reader_handle),
+        };
+
+        engine_process.send(&EngineCommand::Hello)?;
+        engine_process.wait_for(HANDSHAKE_TIMEOUT, |response| {
+            matches!(response, EngineResponse::HelloOk).then_some(())
+        })?;
+
+        Ok(engine_process)
+    }
+
+    fn send(&mut self, engine_command: &EngineCommand) -> io::Result<()> {
+        writeln!(self.stdin, "{}", engine_command)
+            .and_then(|_| self.stdin.flush())
+            .map_err(|_| io::Error::new(io::ErrorKind::UnexpectedEof, "Le moteur s'est arrêté"))
+    }
+
+    fn wait_for<T>(
+        &self,
+        timeout: Duration,
+        mut accept: impl FnMut(EngineResponse) -> Option<T>,
+    ) -> io::Result<T> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.rx_response.recv_timeout(remaining) {
+                Ok(response) => {
+                    if let Some(value) = accept(response) {
+                        return Ok(value);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        "Le moteur n'a pas répondu à temps",
+                    ))
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "Le moteur s'est arrêté",
+                    ))
+                }
+            }
+        }
+    }
+}
+
+impl Drop for EngineProcess {
+    fn drop(&mut self) {
+        let _ = self.send(&EngineCommand::Quit);
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+
+        if let Some(reader_handle) = self.reader_handle.take() {
+            let _ = reader_handle.join();
+        }
+    }
+}
+
+pub struct ExternalEngine {
+    program: String,
+    arguments: Vec<String>,
+    think_time: Duration,
+    process: Option<EngineProcess>,
+    last_error: Option<String>,
+}
+
+impl ExternalEngine {
+    pub fn start(
+        program: &str,
+        arguments: &[String],
+        think_time: Duration,
+    ) -> io::Result<ExternalEngine> {
+        Ok(ExternalEngine {
+            program: program.to_string(),
+            arguments: arguments.to_vec(),
+            think_time,
+            process: Some(EngineProcess::spawn(program, arguments)?),
+            last_error: None,
+        })
+    }
+
+    pub fn from_command_line(
+        command_line: &str,
+        think_time: Duration,
+    ) -> io::Result<ExternalEngine> {
+        let mut words = command_line.split_whitespace();
+        let program = words.next().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "Commande du moteur vide")
+        })?;
+        let arguments: Vec<String> = words.map(String::from).collect();
+
+        ExternalEngine::start(program, &arguments, think_time)
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    fn request_move(&mut self, game_instance: &GameInstance) -> io::Result<Move> {
+        if self.process.is_none() {
+            self.process = Some(EngineProcess::spawn(&self.program, &self.arguments)?);
+        }
+        let process = self.process.as_mut().expect("Moteur non démarré");
+
+        process.send(&EngineCommand::Position(
+            Some(Box::new(game_instance.to_game_state())),
+            Vec::new(),
+        ))?;
+        process.send(&EngineCommand::Go(SearchLimits {
+            move_time: Some(self.think_time),
+            ..SearchLimits::default()
+        }))?;
+
+        let best_move =
+            process.wait_for(
+                self.think_time + RESPONSE_MARGIN,
+                |response| match response {
+                    EngineResponse::BestMove(best_move) => Some(best_move),
+                    _ => None,
+                },
+            )?;
+
+        match best_move {
+            Some(best_move) if game_instance.legal_moves().contains(&best_move) => Ok(best_move),
+            Some(best_move) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Le moteur a joué un coup illégal : {}", best_move),
+            )),
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Le moteur n'a proposé aucun coup",
+            )),
+        }
+    }
+}
+
+impl Strategy for ExternalEngine {
+    fn choose_move(&mut self, game_instance: &GameInstance) -> Option<Move> {
+        match self.request_move(game_instance) {
+            Ok(best_move) => {
+                self.last_error = None;
+                Some(best_move)
+            }
+            Err(error) => {
+                self.last_error = Some(error.to_string());
+                self.process = None;
+                None
+            }
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use crate::ai::external_engine::ExternalEngine;
+    use crate::ai::strategy::Strategy;
+    use crate::model::game_instance::GameInstance;
+    use crate::model::game_move::Move;
+    use crate::model::piece_size::PieceSize::Big;
+    use std::time::{Duration, Instant};
+
+    fn fake_engine(script: &str, think_time: Duration) -> ExternalEngine {
+        ExternalEngine::start("sh", &[String::from("-c"), script.to_string()], think_time).unwrap()
+    }
+
+    const HANDSHAKE: &str = "read line; echo gobbletsok;";
+
+    #[test]
+    fn choose_move_test() {
+        let mut engine = fake_engine(
+            &format!("{} while read line; do case $line in go*) echo info iterations 1; echo bestmove B11;; esac; done", HANDSHAKE),
+            Duration::from_millis(500),
+        );
+
+        assert_eq!(
+            engine.choose_move(&GameInstance::default()),
+            Some(Move::PutPiece(1, 1, Big))
+        );
+        assert_eq!(
+            engine.choose_move(&GameInstance::default()),
+            Some(Move::PutPiece(1, 1, Big))
+        );
+        assert!(engine.last_error().is_none());
+    }
+
+    #[test]
+    fn timeout_forfeit_test() {
+        let mut engine = fake_engine(
+            &format!("{} exec sleep 5", HANDSHAKE),
+            Duration::from_millis(50),
+        );
+
+        let started_at = Instant::now();
+        assert_eq!(engine.choose_move(&GameInstance::default()), None);
+        assert!(started_at.elapsed() < Duration::from_secs(2));
+        assert_eq!(
+            engine.last_error(),
+            Some("Le moteur n'a pas répondu à temps")
+        );
+    }
+
+    #[test]
+    fn crash_and_illegal_move_forfeit_test() {
+        let mut engine = fake_engine(&format!("{} exit 1", HANDSHAKE), Duration::from_millis(500));
+        assert_eq!(engine.choose_move(&GameInstance::default()), None);
+        assert_eq!(engine.last_error(), Some("Le moteur s'est arrêté"));
+
+        let mut engine = fake_engine(
+            &format!(
+                "{} while read line; do case $line in go*) echo bestmove 0011;; esac; done",
+                HANDSHAKE
+            ),
+            Duration::from_millis(500),
+        );
+        assert_eq!(engine.choose_move(&GameInstance::default()), None);
+        assert!(engine.last_error().unwrap().contains("coup illégal"));
+
+        assert!(
+            ExternalEngine::from_command_line("/chemin/inexistant", Duration::from_millis(50))
+                .is_err()
+        );
+    }
+}
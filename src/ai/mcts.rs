@@ -0,0 +1,360 @@
+use crate::ai::random::Random;
+use crate::ai::strategy::Strategy;
+use crate::model::game_instance::GameInstance;
+use crate::model::game_move::Move;
+use crate::model::player::Color;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Playout {
+    Random,
+    Heuristic,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MctsConfig {
+    pub iterations: Option<u32>,
+    pub time_limit: Option<Duration>,
+    pub exploration: f64,
+    pub playout: Playout,
+    pub max_playout_length: u32,
+    pub seed: u64,
+}
+
+impl Default for MctsConfig {
+    fn default() -> Self {
+        MctsConfig {
+            iterations: Some(2_000),
+            time_limit: None,
+            exploration: std::f64::consts::SQRT_2,
+            playout: Playout::Heuristic,
+            max_playout_length: 60,
+            seed: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoveStatistics {
+    pub game_move: Move,
+    pub visits: u32,
+    pub mean_value: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MctsResult {
+    pub best_move: Option<Move>,
+    pub iterations: u32,
+    pub move_statistics: Vec<MoveStatistics>,
+}
+
+struct Node {
+    game_move: Option<Move>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    untried_moves: Vec<Move>,
+    player_just_moved: Color,
+    visits: u32,
+    value: f64,
+}
+
+pub struct Mcts {
+    config: MctsConfig,
+    random: Random,
+}
+
+impl Default for Mcts {
+    fn default() -> Self {
+        Mcts::new(MctsConfig::default())
+    }
+}
+
+impl Mcts {
+    pub fn new(config: MctsConfig) -> Mcts {
+        Mcts {
+            config,
+            random: Random::new(config.seed),
+        }
+    }
+
+    fn budget_is_exhausted(&self, iterations: u32, started_at: Option<Instant>) -> bool {
+        if let Some(max_iterations) = self.config.iterations {
+            if iterations >= max_iterations {
+                return true;
+            }
+        }
+
+        match self.config.time_limit {
+            Some(time_limit) => {
+                started_at.is_some_and(|started_at| started_at.elapsed() >= time_limit)
+            }
+            None => self.config.iterations.is_none() && iterations >= 1,
+        }
+    }
+
+    fn uct_value(&self, parent_visits: u32, child: &Node) -> f64 {
+        if child.visits == 0 {
+            return f64::INFINITY;
+        }
+
+        let visits = child.visits as f64;
+        child.value / visits
+            + self.config.exploration * ((parent_visits as f64).ln() / visits).sqrt()
+    }
+
+    fn select_child(&self, nodes: &[Node], node_index: usize) -> usize {
+        let node = &nodes[node_index];
+        let mut best_child = node.children[0];
+        let mut best_value = f64::NEG_INFINITY;
+
+        for child_index in &node.children {
+            let value = self.uct_value(node.visits, &nodes[*child_index]);
+            if value > best_value {
+                best_value = value;
+                best_child = *child_index;
+            }
+        }
+
+        best_child
+    }
+
+    fn choose_playout_move(&mut self, game_instance: &GameInstance, moves: &[Move]) -> Move {
+        if self.config.playout == Playout::Heuristic {
+            let color = game_instance.get_current_color();
+
+            for game_move in moves {
+                let mut child = game_instance.clone();
+                if child.play(*game_move).is_ok() && child.get_winner() == Some(color) {
+                    return *game_move;
+                }
+            }
+        }
+
+        moves[self.random.next_below(moves.len())]
+    }
+
+    fn playout(&mut self, mut game_instance: GameInstance) -> Option<Color> {
+        for _ in 0..self.config.max_playout_length {
+            if let Some(winner) = game_instance.get_winner() {
+                return Some(winner);
+            }
+
+            let moves = game_instance.legal_moves();
+            if moves.is_empty() {
+                return None;
+            }
+
+            let game_move = self.choose_playout_move(&game_instance, &moves);
+            if game_instance.play(game_move).is_err() {
+                return None;
+            }
+        }
+
+        game_instance.get_winner()
+    }
+
+    pub fn search(&mut self, game_instance: &GameInstance) -> MctsResult {
+        let root_game_instance = game_instance.without_clock();
+        let started_at = self.config.time_limit.map(|_| Instant::now());
+        let mut nodes = vec![Node {
+            game_move: None,
+            parent: None,
+            children: Vec::new(),
+            untried_moves: root_game_instance.legal_moves(),
+            player_just_moved: root_game_instance.get_current_color().opponent(),
+            visits: 0,
+            value: 0.0,
+        }];
+        let mut iterations = 0;
+
+        while !nodes[0].untried_moves.is_empty() || !nodes[0].children.is_empty() {
+            if self.budget_is_exhausted(iterations, started_at) {
+                break;
+            }
+
+            let mut node_index = 0;
+            let mut current_game_instance = root_game_instance.clone();
+
+            while nodes[node_index].untried_moves.is_empty()
+                && !nodes[node_index].children.is_empty()
+            {
+                node_index = self.select_child(&nodes, node_index);
+                let game_move = nodes[node_index].game_move.expect("Nœud sans coup");
+                current_game_instance
+                    .play(game_move)
+                    .expect("Un coup légal a été refusé");
+            }
+
+            if !nodes[node_index].untried_moves.is_empty() {
+                let untried_moves = &mut nodes[node_index].untried_moves;
+                let game_move =
+                    untried_moves.swap_remove(self.random.next_below(untried_moves.len()));
+                let player_just_moved = current_game_instance.get_current_color();
+                current_game_instance
+                    .play(game_move)
+                    .expect("Un coup légal a été refusé");
+
+                nodes.push(Node {
+                    game_move: Some(game_move),
+                    parent: Some(node_index),
+                    children: Vec::new(),
+                    untried_moves: current_game_instance.legal_moves(),
+                    player_just_moved,
+                    visits: 0,
+                    value: 0.0,
+                });
+                let child_index = nodes.len() - 1;
+                nodes[node_index].children.push(child_index);
+                node_index = child_index;
+            }
+
+            let winner = self.playout(current_game_instance);
+
+            let mut backpropagated_node = Some(node_index);
+            while let Some(index) = backpropagated_node {
+                let node = &mut nodes[index];
+                node.visits += 1;
+                node.value += match winner {
+                    Some(color) if color == node.player_just_moved => 1.0,
+                    Some(_) => 0.0,
+                    None => 0.5,
+                };
+                backpropagated_node = node.parent;
+            }
+
+            iterations += 1;
+        }
+
+        let move_statistics: Vec<MoveStatistics> = nodes[0]
+            .children
+            .iter()
+            .map(|child_index| {
+                let child = &nodes[*child_index];
+                MoveStatistics {
+                    game_move: child.game_move.expect("Nœud sans coup"),
+                    visits: child.visits,
+                    mean_value: if child.visits == 0 {
+                        0.0
+                    } else {
+                        child.value / child.visits as f64
+                    },
+                }
+            })
+            .collect();
+
+        let best_move = move_statistics
+            .iter()
+            .max_by_key(|statistics| statistics.visits)
+            .map(|statistics| statistics.game_move)
+            .or_else(|| nodes[0].untried_moves.first().copied());
+
+        MctsResult {
+            best_move,
+            iterations,
+            move_statistics,
+        }
+    }
+}
+
+impl Strategy for Mcts {
+    fn choose_move(&mut self, game_instance: &GameInstance) -> Option<Move> {
+        self.search(game_instance).best_move
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ai::mcts::{Mcts, MctsConfig, Playout};
+    use crate::ai::strategy::Strategy;
+    use crate::model::game_instance::GameInstance;
+    use crate::model::piece_size::PieceSize::{Big, Small};
+    use std::time::Duration;
+
+    fn config(iterations: u32, seed: u64) -> MctsConfig {
+        MctsConfig {
+            iterations: Some(iterations),
+            seed,
+            ..MctsConfig::default()
+        }
+    }
+
+    #[test]
+    fn mcts_visit_counts_test() {
+        let game_instance = GameInstance::default();
+
+        let result = Mcts::new(config(300, 1)).search(&game_instance);
+
+        assert_eq!(result.iterations, 300);
+        assert_eq!(result.move_statistics.len(), 27);
+        assert_eq!(
+            result
+                .move_statistics
+                .iter()
+                .map(|statistics| statistics.visits)
+                .sum::<u32>(),
+            300
+        );
+        assert!(result.best_move.is_some());
+    }
+
+    #[test]
+    fn mcts_is_deterministic_with_seed_test() {
+        let game_instance = GameInstance::default();
+
+        let first_result = Mcts::new(config(200, 5)).search(&game_instance);
+        let second_result = Mcts::new(config(200, 5)).search(&game_instance);
+
+        assert_eq!(first_result.best_move, second_result.best_move);
+        assert_eq!(first_result.move_statistics, second_result.move_statistics);
+    }
+
+    #[test]
+    fn mcts_finds_winning_move_test() {
+        let mut game_instance = GameInstance::default();
+
+        game_instance.put_piece(0, 0, Big).unwrap();
+        game_instance.put_piece(1, 0, Small).unwrap();
+        game_instance.put_piece(0, 1, Big).unwrap();
+        game_instance.put_piece(1, 1, Small).unwrap();
+
+        let mut mcts = Mcts::new(MctsConfig {
+            playout: Playout::Random,
+            ..config(3_000, 3)
+        });
+        let game_move = mcts.choose_move(&game_instance).unwrap();
+
+        game_instance.play(game_move).unwrap();
+        assert!(game_instance.get_winner().is_some());
+    }
+
+    #[test]
+    fn mcts_time_budget_test() {
+        let game_instance = GameInstance::default();
+
+        let result = Mcts::new(MctsConfig {
+            iterations: None,
+            time_limit: Some(Duration::from_millis(20)),
+            ..MctsConfig::default()
+        })
+        .search(&game_instance);
+
+        assert!(result.iterations > 0);
+        assert!(result.best_move.is_some());
+    }
+
+    #[test]
+    fn mcts_finished_game_test() {
+        let mut game_instance = GameInstance::default();
+
+        game_instance.put_piece(0, 0, Big).unwrap();
+        game_instance.put_piece(1, 0, Small).unwrap();
+        game_instance.put_piece(0, 1, Big).unwrap();
+        game_instance.put_piece(1, 1, Small).unwrap();
+        game_instance.put_piece(0, 2, Small).unwrap();
+
+        let result = Mcts::default().search(&game_instance);
+
+        assert!(result.best_move.is_none());
+        assert!(result.move_statistics.is_empty());
+    }
+}
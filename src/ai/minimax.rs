@@ -0,0 +1,184 @@
+use crate::ai::evaluation::{heuristic_score, terminal_score};
+use crate::ai::strategy::Strategy;
+use crate::model::evaluation::{Evaluation, WIN_SCORE};
+use crate::model::game_instance::GameInstance;
+use crate::model::game_move::Move;
+
+const DEFAULT_DEPTH: u32 = 3;
+
+pub struct Minimax {
+    depth: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SearchResult {
+    pub best_move: Option<Move>,
+    pub evaluation: Evaluation,
+}
+
+impl Default for Minimax {
+    fn default() -> Self {
+        Minimax::new(DEFAULT_DEPTH)
+    }
+}
+
+impl Minimax {
+    pub fn new(depth: u32) -> Minimax {
+        Minimax { depth }
+    }
+
+    pub fn search(&self, game_instance: &GameInstance) -> SearchResult {
+        let root = game_instance.without_clock();
+        let mut best_move = None;
+        let mut alpha = -WIN_SCORE - 1;
+
+        if let Some(winner) = root.get_winner() {
+            return SearchResult {
+                best_move,
+                evaluation: Evaluation::from_score(terminal_score(
+                    winner,
+                    root.get_current_color(),
+                    0,
+                )),
+            };
+        }
+
+        for game_move in root.legal_moves() {
+            let mut child = root.clone();
+            if child.play(game_move).is_err() {
+                continue;
+            }
+
+            let score = -self.negamax(
+                &child,
+                self.depth.saturating_sub(1),
+                1,
+                -WIN_SCORE - 1,
+                -alpha,
+            );
+
+            if best_move.is_none() || score > alpha {
+                alpha = score;
+                best_move = Some(game_move);
+            }
+        }
+
+        SearchResult {
+            best_move,
+            evaluation: Evaluation::from_score(if best_move.is_some() { alpha } else { 0 }),
+        }
+    }
+
+    fn negamax(
+        &self,
+        game_instance: &GameInstance,
+        depth: u32,
+        ply: u32,
+        mut alpha: i32,
+        beta: i32,
+    ) -> i32 {
+        let color = game_instance.get_current_color();
+
+        if let Some(winner) = game_instance.get_winner() {
+            return terminal_score(winner, color, ply);
+        }
+
+        if depth == 0 {
+            return heuristic_score(game_instance, color);
+        }
+
+        let mut best_score = None;
+
+        for game_move in game_instance.legal_moves() {
+            let mut child = game_instance.clone();
+            if child.play(game_move).is_err() {
+                continue;
+            }
+
+            let score = -self.negamax(&child, depth - 1, ply + 1, -beta, -alpha);
+            best_score = Some(best_score.map_or(score, |best: i32| best.max(score)));
+            alpha = alpha.max(score);
+
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        best_score.unwrap_or(0)
+    }
+}
+
+impl Strategy for Minimax {
+    fn choose_move(&mut self, game_instance: &GameInstance) -> Option<Move> {
+        self.search(game_instance).best_move
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ai::minimax::Minimax;
+    use crate::model::evaluation::Evaluation;
+    use crate::model::game_instance::GameInstance;
+    use crate::model::game_move::Move;
+    use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+
+    #[test]
+    fn search_start_position_test() {
+        let game_instance = GameInstance::default();
+
+        let search_result = Minimax::new(2).search(&game_instance);
+
+        assert!(search_result.best_move.is_some());
+        assert!(matches!(search_result.evaluation, Evaluation::Score(_)));
+    }
+
+    #[test]
+    fn search_finds_winning_move_test() {
+        let mut game_instance = GameInstance::default();
+
+        game_instance.put_piece(0, 0, Big).unwrap();
+        game_instance.put_piece(1, 0, Small).unwrap();
+        game_instance.put_piece(0, 1, Big).unwrap();
+        game_instance.put_piece(1, 1, Small).unwrap();
+
+        let search_result = Minimax::default().search(&game_instance);
+
+        assert_eq!(search_result.evaluation, Evaluation::Win(1));
+
+        let mut next_game_instance = game_instance.clone();
+        next_game_instance
+            .play(search_result.best_move.unwrap())
+            .unwrap();
+        assert!(next_game_instance.get_winner().is_some());
+    }
+
+    #[test]
+    fn search_blocks_opponent_test() {
+        let mut game_instance = GameInstance::default();
+
+        game_instance.put_piece(1, 0, Small).unwrap();
+        game_instance.put_piece(0, 0, Medium).unwrap();
+        game_instance.put_piece(2, 2, Small).unwrap();
+        game_instance.put_piece(0, 1, Medium).unwrap();
+
+        let search_result = Minimax::new(2).search(&game_instance);
+
+        assert!(!search_result.evaluation.is_loss());
+        assert!(matches!(
+            search_result.best_move,
+            Some(Move::PutPiece(0, 2, _))
+                | Some(Move::MovePiece(_, _, 0, 2))
+                | Some(Move::PutPiece(0, 0, Big))
+                | Some(Move::PutPiece(0, 1, Big))
+        ));
+    }
+
+    #[test]
+    fn search_does_not_change_game_instance_test() {
+        let game_instance = GameInstance::default();
+
+        Minimax::new(2).search(&game_instance);
+
+        assert_eq!(game_instance.to_game_state().turn, 0);
+    }
+}
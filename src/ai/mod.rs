@@ -0,0 +1,12 @@
+pub mod analysis;
+pub mod evaluation;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod external_engine;
+pub mod mcts;
+pub mod minimax;
+pub mod random;
+pub mod random_strategy;
+pub mod solver;
+pub mod strategy;
+pub mod trainer;
+pub mod value_table;
@@ -0,0 +1,58 @@
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Random {
+        Random {
+            state: seed ^ 0x2545_f491_4f6c_dd1d,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        value ^ (value >> 31)
+    }
+
+    pub fn next_below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ai::random::Random;
+
+    #[test]
+    fn random_is_seeded_test() {
+        let mut first_random = Random::new(42);
+        let mut second_random = Random::new(42);
+        let mut other_random = Random::new(43);
+
+        let first_values: Vec<u64> = (0..10).map(|_| first_random.next_u64()).collect();
+        let second_values: Vec<u64> = (0..10).map(|_| second_random.next_u64()).collect();
+        let other_values: Vec<u64> = (0..10).map(|_| other_random.next_u64()).collect();
+
+        assert_eq!(first_values, second_values);
+        assert_ne!(first_values, other_values);
+    }
+
+    #[test]
+    fn random_bounds_test() {
+        let mut random = Random::new(7);
+
+        for _ in 0..1000 {
+            assert!(random.next_below(9) < 9);
+            let value = random.next_f64();
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+}
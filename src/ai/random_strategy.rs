@@ -0,0 +1,28 @@
+use crate::ai::random::Random;
+use crate::ai::strategy::Strategy;
+use crate::model::game_instance::GameInstance;
+use crate::model::game_move::Move;
+
+pub struct RandomStrategy {
+    random: Random,
+}
+
+impl RandomStrategy {
+    pub fn new(seed: u64) -> RandomStrategy {
+        RandomStrategy {
+            random: Random::new(seed),
+        }
+    }
+}
+
+impl Strategy for RandomStrategy {
+    fn choose_move(&mut self, game_instance: &GameInstance) -> Option<Move> {
+        let moves = game_instance.legal_moves();
+
+        if moves.is_empty() {
+            None
+        } else {
+            Some(moves[self.random.next_below(moves.len())])
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use crate::model::game_instance::GameInstance;
+use crate::model::game_move::Move;
+use crate::model::player::Color;
+use crate::model::solution::{Outcome, Solution};
+use std::collections::{HashMap, VecDeque};
+
+const DEFAULT_MAX_POSITIONS: usize = 50_000;
+
+enum NodeKind {
+    Terminal(Outcome),
+    Frontier,
+    Expanded,
+}
+
+struct Node {
+    color: Color,
+    kind: NodeKind,
+    children: Vec<usize>,
+}
+
+struct PositionGraph {
+    nodes: Vec<Node>,
+    root_moves: Vec<Move>,
+}
+
+pub struct Solver {
+    max_positions: usize,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Solver::new(DEFAULT_MAX_POSITIONS)
+    }
+}
+
+impl Solver {
+    pub fn new(max_positions: usize) -> Solver {
+        Solver {
+            max_positions: max_positions.max(1),
+        }
+    }
+
+    pub fn solve(&self, game_instance: &GameInstance) -> Option<Solution> {
+        let graph = self.explore(game_instance);
+        let root_color = graph.nodes[0].color;
+
+        let pessimistic_values = graph.retrograde(root_color.opponent());
+        let optimistic_values = graph.retrograde(root_color);
+
+        let outcome = pessimistic_values[0].map_or(Outcome::Draw, |(outcome, _)| outcome);
+        if optimistic_values[0].map_or(Outcome::Draw, |(outcome, _)| outcome) != outcome {
+            return None;
+        }
+
+        let values = match outcome {
+            Outcome::Loss => &optimistic_values,
+            _ => &pessimistic_values,
+        };
+        let plies = values[0].map_or(0, |(_, plies)| plies);
+
+        let best_move = graph
+            .root_moves
+            .iter()
+            .zip(&graph.nodes[0].children)
+            .find(|(_, child)| {
+                let child_value = values[**child].map(|(child_outcome, child_plies)| {
+                    (child_outcome.from_opponent(), child_plies + 1)
+                });
+                match outcome {
+                    Outcome::Draw => child_value.is_none(),
+                    _ => child_value == Some((outcome, plies)),
+                }
+            })
+            .map(|(game_move, _)| *game_move);
+
+        Some(Solution {
+            outcome,
+            plies,
+            best_move,
+        })
+    }
+
+    fn explore(&self, game_instance: &GameInstance) -> PositionGraph {
+        let root = game_instance.without_clock();
+        let mut indices = HashMap::from([(root.get_hash(), 0)]);
+        let mut nodes = vec![Node::new(&root)];
+        let mut root_moves = Vec::new();
+        let mut queue = VecDeque::from([(0, root)]);
+
+        while let Some((index, game_instance)) = queue.pop_front() {
+            if !matches!(nodes[index].kind, NodeKind::Frontier) {
+                continue;
+            }
+
+            let legal_moves = game_instance.legal_moves();
+            if nodes.len() + legal_moves.len() > self.max_positions {
+                break;
+            }
+
+            let mut children = Vec::with_capacity(legal_moves.len());
+            for game_move in &legal_moves {
+                let mut child = game_instance.clone();
+                if child.play(*game_move).is_err() {
+                    continue;
+                }
+
+                let child_index = *indices.entry(child.get_hash()).or_insert_with(|| {
+                    nodes.push(Node::new(&child));
+                    queue.push_back((nodes.len() - 1, child));
+                    nodes.len() - 1
+                });
+                children.push(child_index);
+
+                if index == 0 {
+                    root_moves.push(*game_move);
+                }
+            }
+
+            nodes[index].kind = NodeKind::Expanded;
+            nodes[index].children = children;
+        }
+
+        PositionGraph { nodes, root_moves }
+    }
+}
+
+impl Node {
+    fn new(game_instance: &GameInstance) -> Node {
+        let color = game_instance.get_current_color();
+
+        Node {
+            color,
+            kind: match game_instance.get_winner() {
+                Some(winner) if winner == color => NodeKind::Terminal(Outcome::Win),
+                Some(_) => NodeKind::Terminal(Outcome::Loss),
+                None => NodeKind::Frontier,
+            },
+            children: Vec::new(),
+        }
+    }
+}
+
+impl PositionGraph {
+    fn retrograde(&self, frontier_winner: Color) -> Vec<Option<(Outcome, u32)>> {
+        let mut values = vec![None; self.nodes.len()];
+        let mut parents = vec![Vec::new(); self.nodes.len()];
+        let mut unresolved_children = vec![0; self.nodes.len()];
+        let mut queue = VecDeque::new();
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            let outcome = match node.kind {
+                NodeKind::Terminal(outcome) => outcome,
+                NodeKind::Frontier if node.color == frontier_winner => Outcome::Win,
+                NodeKind::Frontier => Outcome::Loss,
+                NodeKind::Expanded => {
+                    unresolved_children[index] = node.children.len();
+                    for child in &node.children {
+                        parents[*child].push(index);
+                    }
+                    continue;
+                }
+            };
+
+            values[index] = Some((outcome, 0));
+            queue.push_back(index);
+        }
+
+        while let Some(index) = queue.pop_front() {
+            let (outcome, plies) = values[index].expect("Une position résolue n'a pas de valeur");
+
+            for parent in &parents[index] {
+                if values[*parent].is_some() {
+                    continue;
+                }
+
+                if outcome == Outcome::Loss {
+                    values[*parent] = Some((Outcome::Win, plies + 1));
+                    queue.push_back(*parent);
+                } else {
+                    unresolved_children[*parent] -= 1;
+                    if unresolved_children[*parent] == 0 {
+                        values[*parent] = Some((Outcome::Loss, plies + 1));
+                        queue.push_back(*parent);
+                    }
+                }
+            }
+        }
+
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ai::solver::{Node, NodeKind, PositionGraph, Solver};
+    use crate::model::game_instance::GameInstance;
+    use crate::model::game_move::Move;
+    use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+    use crate::model::player::Color::{Blue, Red};
+    use crate::model::solution::{Outcome, Solution};
+
+    fn play_moves(game_moves: &[Move]) -> GameInstance {
+        let mut game_instance = GameInstance::default();
+        for game_move in game_moves {
+            game_instance.play(*game_move).unwrap();
+        }
+        game_instance
+    }
+
+    #[test]
+    fn solve_immediate_win_test() {
+        let game_instance = play_moves(&[
+            Move::PutPiece(0, 0, Big),
+            Move::PutPiece(2, 0, Big),
+            Move::PutPiece(0, 1, Big),
+            Move::PutPiece(2, 1, Big),
+        ]);
+
+        assert_eq!(
+            Solver::default().solve(&game_instance),
+            Some(Solution {
+                outcome: Outcome::Win,
+                plies: 1,
+                best_move: Some(Move::PutPiece(0, 2, Small)),
+            })
+        );
+    }
+
+    #[test]
+    fn solve_reply_to_blunder_test() {
+        let game_instance = play_moves(&[
+            Move::PutPiece(0, 0, Big),
+            Move::PutPiece(2, 0, Big),
+            Move::PutPiece(0, 1, Big),
+            Move::PutPiece(2, 1, Big),
+            Move::PutPiece(1, 1, Medium),
+        ]);
+
+        assert_eq!(
+            Solver::default().solve(&game_instance),
+            Some(Solution {
+                outcome: Outcome::Win,
+                plies: 1,
+                best_move: Some(Move::PutPiece(2, 2, Small)),
+            })
+        );
+    }
+
+    #[test]
+    fn solve_terminal_position_test() {
+        let game_instance = play_moves(&[
+            Move::PutPiece(0, 0, Big),
+            Move::PutPiece(2, 0, Big),
+            Move::PutPiece(0, 1, Big),
+            Move::PutPiece(2, 1, Big),
+            Move::PutPiece(0, 2, Small),
+        ]);
+
+        assert_eq!(
+            Solver::default().solve(&game_instance),
+            Some(Solution {
+                outcome: Outcome::Loss,
+                plies: 0,
+                best_move: None,
+            })
+        );
+    }
+
+    #[test]
+    fn solve_unknown_when_budget_is_too_small_test() {
+        assert_eq!(Solver::new(1_000).solve(&GameInstance::default()), None);
+    }
+
+    #[test]
+    fn retrograde_test() {
+        let node = |color, kind, children| Node {
+            color,
+            kind,
+            children,
+        };
+        let graph = PositionGraph {
+            nodes: vec![
+                node(Red, NodeKind::Expanded, vec![1, 2]),
+                node(Blue, NodeKind::Expanded, vec![0]),
+                node(Blue, NodeKind::Expanded, vec![3]),
+                node(Red, NodeKind::Terminal(Outcome::Loss), vec![]),
+            ],
+            root_moves: vec![],
+        };
+
+        let values = graph.retrograde(Red);
+
+        assert_eq!(values[0], None);
+        assert_eq!(values[1], None);
+        assert_eq!(values[2], Some((Outcome::Win, 1)));
+
+        let graph = PositionGraph {
+            nodes: vec![
+                node(Red, NodeKind::Expanded, vec![1]),
+                node(Blue, NodeKind::Expanded, vec![0, 2]),
+                node(Red, NodeKind::Terminal(Outcome::Loss), vec![]),
+            ],
+            root_moves: vec![],
+        };
+
+        assert_eq!(graph.retrograde(Red)[0], Some((Outcome::Loss, 2)));
+
+        let graph = PositionGraph {
+            nodes: vec![
+                node(Red, NodeKind::Expanded, vec![1]),
+                node(Blue, NodeKind::Expanded, vec![0, 2]),
+                node(Red, NodeKind::Expanded, vec![1, 3]),
+                node(Blue, NodeKind::Frontier, vec![]),
+            ],
+            root_moves: vec![],
+        };
+
+        assert_eq!(graph.retrograde(Red)[0], None);
+        assert_eq!(graph.retrograde(Red)[2], Some((Outcome::Win, 1)));
+        assert_eq!(graph.retrograde(Blue)[0], None);
+    }
+}
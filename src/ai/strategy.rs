@@ -0,0 +1,68 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ai::external_engine::{ExternalEngine, DEFAULT_THINK_TIME};
+use crate::ai::mcts::Mcts;
+use crate::ai::minimax::Minimax;
+use crate::ai::random_strategy::RandomStrategy;
+use crate::ai::value_table::LearnedStrategy;
+use crate::model::game_instance::GameInstance;
+use crate::model::game_move::Move;
+use std::path::Path;
+
+pub trait Strategy: Send {
+    fn choose_move(&mut self, game_instance: &GameInstance) -> Option<Move>;
+}
+
+pub fn strategy_from_name(name: &str) -> Result<Box<dyn Strategy>, String> {
+    match name {
+        "minimax" => Ok(Box::new(Minimax::default())),
+        "mcts" => Ok(Box::new(Mcts::default())),
+        "random" => Ok(Box::new(RandomStrategy::new(0))),
+        #[cfg(not(target_arch = "wasm32"))]
+        _ if name.starts_with("engine:") => {
+            let command_line = &name["engine:".len()..];
+            ExternalEngine::from_command_line(command_line, DEFAULT_THINK_TIME)
+                .map(|strategy| Box::new(strategy) as Box<dyn Strategy>)
+                .map_err(|error| {
+                    format!(
+                        "Impossible de démarrer le moteur {} : {}",
+                        command_line, error
+                    )
+                })
+        }
+        _ => match name.strip_prefix("learned:") {
+            Some(path) => LearnedStrategy::load(Path::new(path))
+                .map(|strategy| Box::new(strategy) as Box<dyn Strategy>)
+                .map_err(|error| format!("Impossible de charger le modèle {} : {}", path, error)),
+            None => Err(format!("Moteur inconnu : {}", name)),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ai::strategy::strategy_from_name;
+    use crate::model::game_instance::GameInstance;
+
+    #[test]
+    fn strategy_from_name_test() {
+        let game_instance = GameInstance::default();
+
+        for name in ["minimax", "mcts", "random"] {
+            let mut strategy = strategy_from_name(name).unwrap();
+            assert!(strategy.choose_move(&game_instance).is_some());
+        }
+
+        assert_eq!(
+            strategy_from_name("inconnu").err().unwrap(),
+            "Moteur inconnu : inconnu"
+        );
+        assert!(strategy_from_name("learned:/chemin/inexistant")
+            .err()
+            .unwrap()
+            .starts_with("Impossible de charger le modèle /chemin/inexistant : "));
+        assert!(strategy_from_name("engine:/chemin/inexistant")
+            .err()
+            .unwrap()
+            .starts_with("Impossible de démarrer le moteur /chemin/inexistant : "));
+    }
+}
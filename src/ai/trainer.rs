@@ -0,0 +1,266 @@
+use crate::ai::random::Random;
+use crate::ai::strategy::Strategy;
+use crate::ai::value_table::{position_key, LearnedStrategy, ValueTable};
+use crate::model::game_instance::GameInstance;
+use crate::model::player::Color;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy)]
+pub struct TrainerConfig {
+    pub episodes: u32,
+    pub learning_rate: f64,
+    pub exploration_rate: f64,
+    pub max_game_length: u32,
+    pub evaluation_interval: u32,
+    pub evaluation_games: u32,
+    pub seed: u64,
+}
+
+impl Default for TrainerConfig {
+    fn default() -> Self {
+        TrainerConfig {
+            episodes: 10_000,
+            learning_rate: 0.1,
+            exploration_rate: 0.1,
+            max_game_length: 60,
+            evaluation_interval: 1_000,
+            evaluation_games: 100,
+            seed: 0,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct EvaluationReport {
+    pub episode: u32,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+}
+
+impl Display for EvaluationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Épisode {} : {} victoire(s), {} nul(s), {} défaite(s)",
+            self.episode, self.wins, self.draws, self.losses
+        )
+    }
+}
+
+pub struct Trainer {
+    config: TrainerConfig,
+    value_table: ValueTable,
+    random: Random,
+}
+
+fn play_game(
+    red_strategy: &mut dyn Strategy,
+    blue_strategy: &mut dyn Strategy,
+    max_game_length: u32,
+) -> Option<Color> {
+    let mut game_instance = GameInstance::default();
+
+    for _ in 0..max_game_length {
+        if game_instance.get_winner().is_some() {
+            break;
+        }
+
+        let game_move = match game_instance.get_current_color() {
+            Color::Red => red_strategy.choose_move(&game_instance),
+            Color::Blue => blue_strategy.choose_move(&game_instance),
+        };
+
+        match game_move {
+            Some(game_move) if game_instance.play(game_move).is_ok() => {}
+            _ => break,
+        }
+    }
+
+    game_instance.get_winner()
+}
+
+impl Trainer {
+    pub fn new(config: TrainerConfig, value_table: ValueTable) -> Trainer {
+        Trainer {
+            config,
+            value_table,
+            random: Random::new(config.seed),
+        }
+    }
+
+    pub fn get_value_table(&self) -> &ValueTable {
+        &self.value_table
+    }
+
+    pub fn into_value_table(self) -> ValueTable {
+        self.value_table
+    }
+
+    fn self_play_episode(&mut self) {
+        let mut game_instance = GameInstance::default();
+        let mut afterstates = Vec::new();
+
+        for _ in 0..self.config.max_game_length {
+            if game_instance.get_winner().is_some() {
+                break;
+            }
+
+            let moves = game_instance.legal_moves();
+            if moves.is_empty() {
+                break;
+            }
+
+            let game_move = if self.random.next_f64() < self.config.exploration_rate {
+                moves[self.random.next_below(moves.len())]
+            } else {
+                match self.value_table.best_move(&game_instance) {
+                    Some(game_move) => game_move,
+                    None => break,
+                }
+            };
+
+            let color = game_instance.get_current_color();
+            if game_instance.play(game_move).is_err() {
+                break;
+            }
+            afterstates.push((color, position_key(&game_instance)));
+        }
+
+        let winner = game_instance.get_winner();
+        for (color, key) in afterstates {
+            let target = match winner {
+                Some(winner) if winner == color => 1.0,
+                Some(_) => 0.0,
+                None => 0.5,
+            };
+            self.value_table
+                .update(key, target, self.config.learning_rate);
+        }
+    }
+
+    pub fn evaluate(&self, baseline: &mut dyn Strategy, episode: u32) -> EvaluationReport {
+        let mut report = EvaluationReport {
+            episode,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+        };
+        let mut learned_strategy = LearnedStrategy::new(self.value_table.clone());
+
+        for game_index in 0..self.config.evaluation_games {
+            let learned_color = if game_index % 2 == 0 {
+                Color::Red
+            } else {
+                Color::Blue
+            };
+
+            let winner = match learned_color {
+                Color::Red => {
+                    play_game(&mut learned_strategy, baseline, self.config.max_game_length)
+                }
+                Color::Blue => {
+                    play_game(baseline, &mut learned_strategy, self.config.max_game_length)
+                }
+            };
+
+            match winner {
+                Some(winner) if winner == learned_color => report.wins += 1,
+                Some(_) => report.losses += 1,
+                None => report.draws += 1,
+            }
+        }
+
+        report
+    }
+
+    pub fn train<F>(&mut self, baseline: &mut dyn Strategy, mut on_evaluation: F)
+    where
+        F: FnMut(&EvaluationReport, &ValueTable),
+    {
+        for episode in 1..=self.config.episodes {
+            self.self_play_episode();
+
+            if self.config.evaluation_interval > 0
+                && (episode % self.config.evaluation_interval == 0
+                    || episode == self.config.episodes)
+            {
+                let report = self.evaluate(baseline, episode);
+                on_evaluation(&report, &self.value_table);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ai::random_strategy::RandomStrategy;
+    use crate::ai::trainer::{Trainer, TrainerConfig};
+    use crate::ai::value_table::ValueTable;
+
+    fn config(episodes: u32) -> TrainerConfig {
+        TrainerConfig {
+            episodes,
+            evaluation_interval: 50,
+            evaluation_games: 10,
+            seed: 3,
+            ..TrainerConfig::default()
+        }
+    }
+
+    #[test]
+    fn train_fills_value_table_test() {
+        let mut trainer = Trainer::new(config(100), ValueTable::default());
+        let mut reports = Vec::new();
+
+        trainer.train(&mut RandomStrategy::new(1), |report, _| {
+            reports.push(*report)
+        });
+
+        assert!(!trainer.get_value_table().is_empty());
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[1].episode, 100);
+        assert_eq!(reports[1].wins + reports[1].draws + reports[1].losses, 10);
+    }
+
+    #[test]
+    fn train_is_deterministic_with_seed_test() {
+        let mut first_trainer = Trainer::new(config(50), ValueTable::default());
+        let mut second_trainer = Trainer::new(config(50), ValueTable::default());
+        let mut first_reports = Vec::new();
+        let mut second_reports = Vec::new();
+
+        first_trainer.train(&mut RandomStrategy::new(1), |report, _| {
+            first_reports.push(*report)
+        });
+        second_trainer.train(&mut RandomStrategy::new(1), |report, _| {
+            second_reports.push(*report)
+        });
+
+        assert_eq!(first_reports, second_reports);
+        assert_eq!(
+            first_trainer.get_value_table().len(),
+            second_trainer.get_value_table().len()
+        );
+    }
+
+    #[test]
+    fn training_beats_random_baseline_test() {
+        let config = TrainerConfig {
+            episodes: 1_000,
+            evaluation_interval: 0,
+            evaluation_games: 100,
+            seed: 7,
+            ..TrainerConfig::default()
+        };
+        let mut trainer = Trainer::new(config, ValueTable::default());
+        let untrained_report = trainer.evaluate(&mut RandomStrategy::new(11), 0);
+
+        trainer.train(&mut RandomStrategy::new(11), |_, _| {});
+        let trained_report = trainer.evaluate(&mut RandomStrategy::new(11), config.episodes);
+
+        assert!(trained_report.wins > 9 * trained_report.losses);
+        assert!(trained_report.losses < untrained_report.losses);
+    }
+}
@@ -0,0 +1,242 @@
+use crate::ai::strategy::Strategy;
+use crate::model::game_instance::GameInstance;
+use crate::model::game_move::Move;
+use crate::model::piece_size::PieceSize;
+use crate::model::player::Color;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::io::ErrorKind;
+use std::path::Path;
+
+const FILE_HEADER: &str = "gobblets-gobblers value-table v1";
+const UNKNOWN_VALUE: f64 = 0.5;
+
+pub fn position_key(game_instance: &GameInstance) -> u128 {
+    let game_state = game_instance.to_game_state();
+    let mut key = game_state.board.canonical_key();
+
+    for player_state in &game_state.players {
+        for piece_size in [PieceSize::Small, PieceSize::Medium, PieceSize::Big] {
+            let count = player_state
+                .pieces
+                .iter()
+                .filter(|reserve_piece_size| **reserve_piece_size == piece_size)
+                .count();
+            key = (key << 2) | count as u128;
+        }
+    }
+
+    (key << 1) | (game_instance.get_current_color() == Color::Blue) as u128
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ValueTable {
+    values: HashMap<u128, f64>,
+}
+
+impl ValueTable {
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn get_value(&self, key: u128) -> f64 {
+        self.values.get(&key).copied().unwrap_or(UNKNOWN_VALUE)
+    }
+
+    pub fn update(&mut self, key: u128, target: f64, learning_rate: f64) {
+        let value = self.values.entry(key).or_insert(UNKNOWN_VALUE);
+        *value += learning_rate * (target - *value);
+    }
+
+    pub fn evaluate_move(&self, game_instance: &GameInstance, game_move: Move) -> Option<f64> {
+        let color = game_instance.get_current_color();
+        let mut child = game_instance.without_clock();
+        child.play(game_move).ok()?;
+
+        Some(match child.get_winner() {
+            Some(winner) if winner == color => 1.0,
+            Some(_) => 0.0,
+            None => self.get_value(position_key(&child)),
+        })
+    }
+
+    pub fn best_move(&self, game_instance: &GameInstance) -> Option<Move> {
+        let mut best_move = None;
+        let mut best_value = f64::NEG_INFINITY;
+
+        for game_move in game_instance.legal_moves() {
+            if let Some(value) = self.evaluate_move(game_instance, game_move) {
+                if value > best_value {
+                    best_value = value;
+                    best_move = Some(game_move);
+                }
+            }
+        }
+
+        best_move
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut entries: Vec<_> = self.values.iter().collect();
+        entries.sort_by_key(|(key, _)| **key);
+
+        let mut content = String::from(FILE_HEADER);
+        content.push('\n');
+        for (key, value) in entries {
+            content.push_str(&format!("{:x} {}\n", key, value));
+        }
+
+        fs::write(path, content)
+    }
+
+    pub fn load(path: &Path) -> io::Result<ValueTable> {
+        let content = fs::read_to_string(path)?;
+        let mut lines = content.lines();
+
+        if lines.next() != Some(FILE_HEADER) {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "En-tête du modèle invalide",
+            ));
+        }
+
+        let mut values = HashMap::new();
+        for line in lines.filter(|line| !line.trim().is_empty()) {
+            let invalid_line =
+                || io::Error::new(ErrorKind::InvalidData, format!("Ligne invalide : {}", line));
+            let (key, value) = line.split_once(' ').ok_or_else(invalid_line)?;
+            let key = u128::from_str_radix(key, 16).map_err(|_| invalid_line())?;
+            let value = value.parse::<f64>().map_err(|_| invalid_line())?;
+            values.insert(key, value);
+        }
+
+        Ok(ValueTable { values })
+    }
+}
+
+pub struct LearnedStrategy {
+    value_table: ValueTable,
+}
+
+impl LearnedStrategy {
+    pub fn new(value_table: ValueTable) -> LearnedStrategy {
+        LearnedStrategy { value_table }
+    }
+
+    pub fn load(path: &Path) -> io::Result<LearnedStrategy> {
+        ValueTable::load(path).map(LearnedStrategy::new)
+    }
+}
+
+impl Strategy for LearnedStrategy {
+    fn choose_move(&mut self, game_instance: &GameInstance) -> Option<Move> {
+        self.value_table.best_move(game_instance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ai::strategy::Strategy;
+    use crate::ai::value_table::{position_key, LearnedStrategy, ValueTable};
+    use crate::model::game_instance::GameInstance;
+    use crate::model::game_move::Move;
+    use crate::model::piece_size::PieceSize::{Big, Small};
+
+    #[test]
+    fn position_key_is_canonical_test() {
+        let mut first_game_instance = GameInstance::default();
+        first_game_instance
+            .play(Move::PutPiece(0, 0, Small))
+            .unwrap();
+
+        let mut second_game_instance = GameInstance::default();
+        second_game_instance
+            .play(Move::PutPiece(2, 2, Small))
+            .unwrap();
+
+        let mut third_game_instance = GameInstance::default();
+        third_game_instance
+            .play(Move::PutPiece(0, 1, Small))
+            .unwrap();
+
+        assert_eq!(
+            position_key(&first_game_instance),
+            position_key(&second_game_instance)
+        );
+        assert_ne!(
+            position_key(&first_game_instance),
+            position_key(&third_game_instance)
+        );
+        assert_ne!(
+            position_key(&first_game_instance),
+            position_key(&GameInstance::default())
+        );
+    }
+
+    #[test]
+    fn value_table_update_test() {
+        let mut value_table = ValueTable::default();
+
+        assert_eq!(value_table.get_value(42), 0.5);
+
+        value_table.update(42, 1.0, 0.5);
+
+        assert_eq!(value_table.get_value(42), 0.75);
+        assert_eq!(value_table.len(), 1);
+    }
+
+    #[test]
+    fn value_table_save_load_test() {
+        let mut value_table = ValueTable::default();
+        value_table.update(1, 1.0, 0.25);
+        value_table.update(u128::MAX, 0.0, 0.5);
+        let path = std::env::temp_dir().join(format!(
+            "gobblets_gobblers_value_table_test_{}.txt",
+            std::process::id()
+        ));
+
+        value_table.save(&path).unwrap();
+        let loaded_value_table = ValueTable::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded_value_table.len(), 2);
+        assert_eq!(loaded_value_table.get_value(1), value_table.get_value(1));
+        assert_eq!(
+            loaded_value_table.get_value(u128::MAX),
+            value_table.get_value(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn value_table_load_invalid_file_test() {
+        let path = std::env::temp_dir().join(format!(
+            "gobblets_gobblers_invalid_value_table_test_{}.txt",
+            std::process::id()
+        ));
+        std::fs::write(&path, "pas un modèle\n").unwrap();
+
+        assert!(ValueTable::load(&path).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn learned_strategy_takes_winning_move_test() {
+        let mut game_instance = GameInstance::default();
+
+        game_instance.put_piece(0, 0, Big).unwrap();
+        game_instance.put_piece(1, 0, Small).unwrap();
+        game_instance.put_piece(0, 1, Big).unwrap();
+        game_instance.put_piece(1, 1, Small).unwrap();
+
+        let mut strategy = LearnedStrategy::new(ValueTable::default());
+        let game_move = strategy.choose_move(&game_instance).unwrap();
+
+        game_instance.play(game_move).unwrap();
+        assert!(game_instance.get_winner().is_some());
+    }
+}
@@ -0,0 +1,10 @@
+use gobblets_gobblers::engine::engine::Engine;
+use std::io;
+use std::process;
+
+fn main() {
+    if let Err(error) = Engine::new().run(io::stdin().lock(), &mut io::stdout()) {
+        eprintln!("Erreur de communication : {}", error);
+        process::exit(1);
+    }
+}
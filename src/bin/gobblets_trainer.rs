@@ -0,0 +1,77 @@
+use gobblets_gobblers::ai::random_strategy::RandomStrategy;
+use gobblets_gobblers::ai::trainer::{Trainer, TrainerConfig};
+use gobblets_gobblers::ai::value_table::ValueTable;
+use std::path::Path;
+use std::{env, process};
+
+const DEFAULT_MODEL_PATH: &str = "model.txt";
+
+fn get_option_value<'a>(args: &'a [String], option: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|arg| arg == option)
+        .and_then(|index| args.get(index + 1))
+        .map(|value| value.as_str())
+}
+
+fn load_value_table(model_path: &Path) -> ValueTable {
+    if !model_path.exists() {
+        return ValueTable::default();
+    }
+
+    match ValueTable::load(model_path) {
+        Ok(value_table) => {
+            println!(
+                "Modèle chargé depuis {} ({} positions)",
+                model_path.display(),
+                value_table.len()
+            );
+            value_table
+        }
+        Err(error) => {
+            eprintln!("Impossible de charger le modèle : {}", error);
+            process::exit(1);
+        }
+    }
+}
+
+fn run_training(episodes: u32, model_path: &Path, seed: u64) {
+    let mut trainer = Trainer::new(
+        TrainerConfig {
+            episodes,
+            seed,
+            ..TrainerConfig::default()
+        },
+        load_value_table(model_path),
+    );
+    let mut baseline = RandomStrategy::new(seed);
+
+    trainer.train(&mut baseline, |report, value_table| {
+        println!("{} ({} positions)", report, value_table.len());
+        if let Err(error) = value_table.save(model_path) {
+            eprintln!("Impossible de sauvegarder le modèle : {}", error);
+        }
+    });
+
+    println!("Modèle sauvegardé dans {}", model_path.display());
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let episodes = args.first().map(|value| value.parse::<u32>());
+    let seed = get_option_value(&args, "--seed")
+        .map(str::parse::<u64>)
+        .unwrap_or(Ok(0));
+
+    match (episodes, seed) {
+        (Some(Ok(episodes)), Ok(seed)) => run_training(
+            episodes,
+            Path::new(get_option_value(&args, "--model").unwrap_or(DEFAULT_MODEL_PATH)),
+            seed,
+        ),
+        _ => {
+            eprintln!("Usage : gobblets-trainer <épisodes> [--model <fichier>] [--seed <graine>]");
+            process::exit(2);
+        }
+    }
+}
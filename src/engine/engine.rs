@@ -0,0 +1,192 @@
+use crate::ai::mcts::{Mcts, MctsConfig};
+use crate::ai::minimax::Minimax;
+use crate::engine::engine_protocol::{
+    parse_engine_command, EngineCommand, EngineResponse, SearchLimits,
+};
+use crate::model::evaluation::Evaluation;
+use crate::model::game_instance::GameInstance;
+use crate::model::game_move::Move;
+use crate::model::game_state::GameState;
+use std::io;
+use std::io::{BufRead, Write};
+use std::time::Instant;
+
+#[derive(Default)]
+pub struct Engine {
+    game_instance: GameInstance,
+}
+
+fn score_to_protocol(evaluation: Evaluation) -> String {
+    match evaluation {
+        Evaluation::Win(plies) => format!("win {}", plies),
+        Evaluation::Loss(plies) => format!("loss {}", plies),
+        Evaluation::Score(score) => format!("cp {}", score),
+    }
+}
+
+impl Engine {
+    pub fn new() -> Engine {
+        Engine::default()
+    }
+
+    pub fn run<R: BufRead, W: Write>(&mut self, input: R, output: &mut W) -> io::Result<()> {
+        for line in input.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let responses = match parse_engine_command(&line) {
+                Ok(EngineCommand::Quit) => break,
+                Ok(engine_command) => self.execute(engine_command),
+                Err(error) => vec![EngineResponse::Info(format!("string {}", error))],
+            };
+
+            for response in responses {
+                writeln!(output, "{}", response)?;
+            }
+            output.flush()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn execute(&mut self, engine_command: EngineCommand) -> Vec<EngineResponse> {
+        match engine_command {
+            EngineCommand::Hello => vec![
+                EngineResponse::Id(format!(
+                    "name {} {}",
+                    env!("CARGO_PKG_NAME"),
+                    env!("CARGO_PKG_VERSION")
+                )),
+                EngineResponse::HelloOk,
+            ],
+            EngineCommand::IsReady => vec![EngineResponse::ReadyOk],
+            EngineCommand::NewGame => {
+                self.game_instance = GameInstance::default();
+                Vec::new()
+            }
+            EngineCommand::Position(position, moves) => match self.set_position(position, &moves) {
+                Ok(()) => Vec::new(),
+                Err(error) => vec![EngineResponse::Info(format!("string {}", error))],
+            },
+            EngineCommand::Go(search_limits) => self.go(search_limits),
+            EngineCommand::Quit => Vec::new(),
+        }
+    }
+
+    fn set_position(
+        &mut self,
+        position: Option<Box<GameState>>,
+        moves: &[Move],
+    ) -> Result<(), String> {
+        let mut game_instance = match position {
+            Some(game_state) => GameInstance::from_game_state(&game_state)
+                .map_err(|error| format!("Position invalide : {}", error))?,
+            None => GameInstance::default(),
+        };
+
+        for game_move in moves {
+            game_instance
+                .play(*game_move)
+                .map_err(|error| format!("Coup refusé : {}", error))?;
+        }
+
+        self.game_instance = game_instance;
+        Ok(())
+    }
+
+    fn go(&self, search_limits: SearchLimits) -> Vec<EngineResponse> {
+        if self.game_instance.get_winner().is_some() {
+            return vec![EngineResponse::BestMove(None)];
+        }
+
+        let started_at = Instant::now();
+
+        if search_limits.move_time.is_none() && search_limits.iterations.is_none() {
+            let minimax = match search_limits.depth {
+                Some(depth) => Minimax::new(depth),
+                None => Minimax::default(),
+            };
+            let search_result = minimax.search(&self.game_instance);
+
+            let depth = search_limits
+                .depth
+                .map(|depth| format!("depth {} ", depth))
+                .unwrap_or_default();
+
+            return vec![
+                EngineResponse::Info(format!(
+                    "{}score {} time {}",
+                    depth,
+                    score_to_protocol(search_result.evaluation),
+                    started_at.elapsed().as_millis()
+                )),
+                EngineResponse::BestMove(search_result.best_move),
+            ];
+        }
+
+        let mut mcts = Mcts::new(MctsConfig {
+            iterations: search_limits.iterations,
+            time_limit: search_limits.move_time,
+            ..MctsConfig::default()
+        });
+        let mcts_result = mcts.search(&self.game_instance);
+
+        vec![
+            EngineResponse::Info(format!(
+                "iterations {} time {}",
+                mcts_result.iterations,
+                started_at.elapsed().as_millis()
+            )),
+            EngineResponse::BestMove(mcts_result.best_move),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::engine::Engine;
+
+    fn run_engine(input: &str) -> String {
+        let mut output = Vec::new();
+        Engine::new().run(input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn handshake_test() {
+        let output = run_engine("gobblets\nisready\nquit\nisready\n");
+
+        assert!(output.starts_with("id name gobblets-gobblers "));
+        assert!(output.ends_with("\ngobbletsok\nreadyok\n"));
+    }
+
+    #[test]
+    fn search_winning_move_test() {
+        let output = run_engine(
+            "position startpos moves B00 S10 B11 S20\ngo depth 2\nposition pos B,-,-/s,B,-/s,-,- SSMM mmbb r 4\ngo iterations 500\n",
+        );
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("info depth 2 score win 1"));
+        assert_eq!(lines[1], "bestmove S22");
+        assert!(lines[2].starts_with("info iterations "));
+        assert!(lines[3].starts_with("bestmove "));
+    }
+
+    #[test]
+    fn invalid_input_test() {
+        let output = run_engine(
+            "position startpos moves B00 B00\ngo movetime\nposition pos B,B,M/s,s,-/-,-,- SSM mmbb b 5\ngo\n",
+        );
+
+        assert_eq!(
+            output,
+            "info string Coup refusé : La pièce est trop petite pour être placer ici\n\
+             info string Argument manquant\n\
+             bestmove none\n"
+        );
+    }
+}
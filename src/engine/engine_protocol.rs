@@ -0,0 +1,312 @@
+use crate::model::game_move::Move;
+use crate::model::game_state::GameState;
+use crate::model::piece_size::PieceSize;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+pub const PROTOCOL_NAME: &str = "gobblets";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SearchLimits {
+    pub depth: Option<u32>,
+    pub move_time: Option<Duration>,
+    pub iterations: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub enum EngineCommand {
+    Hello,
+    IsReady,
+    NewGame,
+    Position(Option<Box<GameState>>, Vec<Move>),
+    Go(SearchLimits),
+    Quit,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EngineResponse {
+    Id(String),
+    HelloOk,
+    ReadyOk,
+    Info(String),
+    BestMove(Option<Move>),
+}
+
+fn piece_size_letter(piece_size: PieceSize) -> char {
+    match piece_size {
+        PieceSize::Small => 'S',
+        PieceSize::Medium => 'M',
+        PieceSize::Big => 'B',
+    }
+}
+
+fn parse_coordinate(coordinate: char) -> Result<usize, String> {
+    match coordinate.to_digit(10) {
+        Some(value) if value < 3 => Ok(value as usize),
+        _ => Err(format!("Coordonnée invalide : {}", coordinate)),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(value: Option<&str>) -> Result<T, String> {
+    let value = value.ok_or_else(|| String::from("Argument manquant"))?;
+    value
+        .parse()
+        .map_err(|_| format!("Nombre invalide : {}", value))
+}
+
+pub fn move_to_notation(game_move: &Move) -> String {
+    match *game_move {
+        Move::PutPiece(x, y, piece_size) => format!("{}{}{}", piece_size_letter(piece_size), x, y),
+        Move::MovePiece(origin_x, origin_y, destination_x, destination_y) => {
+            format!("{}{}{}{}", origin_x, origin_y, destination_x, destination_y)
+        }
+    }
+}
+
+pub fn move_from_notation(notation: &str) -> Result<Move, String> {
+    let characters: Vec<char> = notation.chars().collect();
+
+    match characters[..] {
+        [size, x, y] => {
+            let piece_size = match size {
+                'S' => PieceSize::Small,
+                'M' => PieceSize::Medium,
+                'B' => PieceSize::Big,
+                _ => return Err(format!("Taille de pièce inconnue : {}", size)),
+            };
+            Ok(Move::PutPiece(
+                parse_coordinate(x)?,
+                parse_coordinate(y)?,
+                piece_size,
+            ))
+        }
+        [origin_x, origin_y, destination_x, destination_y] => Ok(Move::MovePiece(
+            parse_coordinate(origin_x)?,
+            parse_coordinate(origin_y)?,
+            parse_coordinate(destination_x)?,
+            parse_coordinate(destination_y)?,
+        )),
+        _ => Err(format!("Coup invalide : {}", notation)),
+    }
+}
+
+fn parse_position(arguments: &[&str]) -> Result<EngineCommand, String> {
+    let moves_index = arguments
+        .iter()
+        .position(|argument| *argument == "moves")
+        .unwrap_or(arguments.len());
+
+    let position = match arguments[..moves_index] {
+        ["startpos"] => None,
+        ["pos", ref fields @ ..] => Some(Box::new(
+            fields
+                .join(" ")
+                .parse::<GameState>()
+                .map_err(|error| format!("Position invalide : {}", error))?,
+        )),
+        _ => {
+            return Err(String::from(
+                "Usage : position <startpos|pos <position>> [moves <coups>]",
+            ))
+        }
+    };
+    let moves = arguments
+        .iter()
+        .skip(moves_index + 1)
+        .map(|notation| move_from_notation(notation))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(EngineCommand::Position(position, moves))
+}
+
+fn parse_search_limits(arguments: &[&str]) -> Result<SearchLimits, String> {
+    let mut search_limits = SearchLimits::default();
+    let mut arguments = arguments.iter().copied();
+
+    while let Some(limit) = arguments.next() {
+        match limit {
+            "depth" => search_limits.depth = Some(parse_number(arguments.next())?),
+            "movetime" => {
+                search_limits.move_time =
+                    Some(Duration::from_millis(parse_number(arguments.next())?))
+            }
+            "iterations" => search_limits.iterations = Some(parse_number(arguments.next())?),
+            _ => return Err(format!("Limite de recherche inconnue : {}", limit)),
+        }
+    }
+
+    Ok(search_limits)
+}
+
+pub fn parse_engine_command(line: &str) -> Result<EngineCommand, String> {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+
+    match tokens[..] {
+        [PROTOCOL_NAME] => Ok(EngineCommand::Hello),
+        ["isready"] => Ok(EngineCommand::IsReady),
+        ["newgame"] => Ok(EngineCommand::NewGame),
+        ["position", ref arguments @ ..] => parse_position(arguments),
+        ["go", ref arguments @ ..] => parse_search_limits(arguments).map(EngineCommand::Go),
+        ["quit"] => Ok(EngineCommand::Quit),
+        _ => Err(format!("Commande inconnue : {}", line.trim())),
+    }
+}
+
+pub fn parse_engine_response(line: &str) -> Result<EngineResponse, String> {
+    let line = line.trim();
+    let (keyword, arguments) = line.split_once(' ').unwrap_or((line, ""));
+
+    match keyword {
+        "id" => Ok(EngineResponse::Id(arguments.to_string())),
+        "readyok" => Ok(EngineResponse::ReadyOk),
+        "info" => Ok(EngineResponse::Info(arguments.to_string())),
+        "bestmove" => match arguments.trim() {
+            "none" => Ok(EngineResponse::BestMove(None)),
+            notation => move_from_notation(notation)
+                .map(|game_move| EngineResponse::BestMove(Some(game_move))),
+        },
+        _ if keyword == format!("{}ok", PROTOCOL_NAME) => Ok(EngineResponse::HelloOk),
+        _ => Err(format!("Réponse inconnue : {}", line)),
+    }
+}
+
+impl Display for EngineCommand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            EngineCommand::Hello => write!(f, "{}", PROTOCOL_NAME),
+            EngineCommand::IsReady => write!(f, "isready"),
+            EngineCommand::NewGame => write!(f, "newgame"),
+            EngineCommand::Position(position, moves) => {
+                match position {
+                    Some(game_state) => write!(f, "position pos {}", game_state)?,
+                    None => write!(f, "position startpos")?,
+                }
+                if !moves.is_empty() {
+                    write!(f, " moves")?;
+                    for game_move in moves {
+                        write!(f, " {}", move_to_notation(game_move))?;
+                    }
+                }
+                Ok(())
+            }
+            EngineCommand::Go(search_limits) => {
+                write!(f, "go")?;
+                if let Some(depth) = search_limits.depth {
+                    write!(f, " depth {}", depth)?;
+                }
+                if let Some(move_time) = search_limits.move_time {
+                    write!(f, " movetime {}", move_time.as_millis())?;
+                }
+                if let Some(iterations) = search_limits.iterations {
+                    write!(f, " iterations {}", iterations)?;
+                }
+                Ok(())
+            }
+            EngineCommand::Quit => write!(f, "quit"),
+        }
+    }
+}
+
+impl Display for EngineResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            EngineResponse::Id(id) => write!(f, "id {}", id),
+            EngineResponse::HelloOk => write!(f, "{}ok", PROTOCOL_NAME),
+            EngineResponse::ReadyOk => write!(f, "readyok"),
+            EngineResponse::Info(info) => write!(f, "info {}", info),
+            EngineResponse::BestMove(Some(game_move)) => {
+                write!(f, "bestmove {}", move_to_notation(game_move))
+            }
+            EngineResponse::BestMove(None) => write!(f, "bestmove none"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::engine_protocol::{
+        move_from_notation, move_to_notation, parse_engine_command, parse_engine_response,
+        EngineCommand, EngineResponse, SearchLimits,
+    };
+    use crate::model::game_move::Move;
+    use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+    use std::time::Duration;
+
+    #[test]
+    fn move_notation_test() {
+        for game_move in [
+            Move::PutPiece(1, 2, Big),
+            Move::PutPiece(0, 0, Small),
+            Move::MovePiece(0, 1, 2, 2),
+        ] {
+            assert_eq!(
+                move_from_notation(&move_to_notation(&game_move)),
+                Ok(game_move)
+            );
+        }
+
+        assert_eq!(move_to_notation(&Move::PutPiece(1, 2, Big)), "B12");
+        assert_eq!(move_to_notation(&Move::MovePiece(0, 1, 2, 2)), "0122");
+        assert!(move_from_notation("X12").is_err());
+        assert!(move_from_notation("B13").is_err());
+        assert!(move_from_notation("01").is_err());
+    }
+
+    #[test]
+    fn engine_command_round_trip_test() {
+        let lines = [
+            "gobblets",
+            "isready",
+            "newgame",
+            "position startpos",
+            "position startpos moves B11 S00 1100",
+            "position pos B,-,-/-,-,-/-,-,- SSMMB ssmmbb b 1 moves s22",
+            "go",
+            "go depth 4",
+            "go movetime 500 iterations 1000",
+            "quit",
+        ];
+
+        for line in lines.iter().filter(|line| !line.contains("s22")) {
+            assert_eq!(parse_engine_command(line).unwrap().to_string(), *line);
+        }
+
+        assert!(parse_engine_command(lines[5]).is_err());
+        assert!(matches!(
+            parse_engine_command("position pos B,-,-/-,-,-/-,-,- SSMMB ssmmbb b 1 moves S22"),
+            Ok(EngineCommand::Position(Some(_), moves)) if moves == vec![Move::PutPiece(2, 2, Small)]
+        ));
+        assert!(matches!(
+            parse_engine_command("go movetime 500 iterations 1000"),
+            Ok(EngineCommand::Go(SearchLimits { depth: None, move_time: Some(move_time), iterations: Some(1000) }))
+                if move_time == Duration::from_millis(500)
+        ));
+        assert!(parse_engine_command("go depth").is_err());
+        assert!(parse_engine_command("position").is_err());
+        assert!(parse_engine_command("bonjour").is_err());
+    }
+
+    #[test]
+    fn engine_response_round_trip_test() {
+        let responses = [
+            EngineResponse::Id(String::from("name gobblets-gobblers")),
+            EngineResponse::HelloOk,
+            EngineResponse::ReadyOk,
+            EngineResponse::Info(String::from("depth 3 score cp 12")),
+            EngineResponse::BestMove(Some(Move::MovePiece(0, 0, 1, 1))),
+            EngineResponse::BestMove(None),
+        ];
+
+        for response in responses {
+            assert_eq!(parse_engine_response(&response.to_string()), Ok(response));
+        }
+
+        assert_eq!(
+            parse_engine_response("bestmove M20\n"),
+            Ok(EngineResponse::BestMove(Some(Move::PutPiece(2, 0, Medium))))
+        );
+        assert!(parse_engine_response("bestmove 9").is_err());
+        assert!(parse_engine_response("bonjour").is_err());
+    }
+}
@@ -0,0 +1,3 @@
+#[allow(clippy::module_inception)]
+pub mod engine;
+pub mod engine_protocol;
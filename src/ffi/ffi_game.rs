@@ -0,0 +1,412 @@
+use crate::model::game_error::GameError;
+use crate::model::game_instance::GameInstance;
+use crate::model::game_state::{GameState, PieceState, PlayerState};
+use crate::model::piece_size::PieceSize;
+use crate::model::player::Color;
+use std::ffi::{c_char, CStr};
+use std::ptr;
+
+const BOARD_SIZE: u32 = 3;
+/// Une case empile au plus une pièce de chaque taille.
+pub const GOBBLETS_MAX_STACK_HEIGHT: usize = 3;
+
+/// Partie manipulée à travers les règles du jeu uniquement : les commandes,
+/// événements, listeners, bots et matchs de `Game` ne sont pas exposés en C.
+pub struct GobbletsGame {
+    game_instance: GameInstance,
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum GobbletsError {
+    Ok = 0,
+    CurrentlyNoGame = 1,
+    CurrentlyNoMatch = 2,
+    CannotPutPieceHere = 3,
+    SquareIsEmpty = 4,
+    PieceNotAvailable = 5,
+    NotYourPiece = 6,
+    GameIsOver = 7,
+    InvalidPosition = 8,
+    UnknownError = 9,
+    InvalidArgument = 10,
+    UnsupportedCommand = 11,
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum GobbletsColor {
+    Red = 0,
+    Blue = 1,
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum GobbletsPieceSize {
+    Small = 0,
+    Medium = 1,
+    Big = 2,
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum GobbletsOutcome {
+    InProgress = 0,
+    RedWins = 1,
+    BlueWins = 2,
+    Draw = 3,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GobbletsPiece {
+    pub color: GobbletsColor,
+    pub size: GobbletsPieceSize,
+}
+
+/// Pile d'une case, de la pièce du dessous à la pièce visible.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GobbletsSquare {
+    pub height: u32,
+    pub pieces: [GobbletsPiece; GOBBLETS_MAX_STACK_HEIGHT],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GobbletsPlayer {
+    pub color: GobbletsColor,
+    pub small_pieces: u32,
+    pub medium_pieces: u32,
+    pub big_pieces: u32,
+    /// Temps restant en millisecondes, ou -1 pour une partie sans pendule.
+    pub remaining_time_ms: i64,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GobbletsState {
+    pub squares: [[GobbletsSquare; 3]; 3],
+    pub players: [GobbletsPlayer; 2],
+    pub turn: u32,
+    pub current_color: GobbletsColor,
+    pub outcome: GobbletsOutcome,
+}
+
+impl From<&GameError> for GobbletsError {
+    fn from(game_error: &GameError) -> Self {
+        match game_error {
+            GameError::CurrentlyNoGame(_) => GobbletsError::CurrentlyNoGame,
+            GameError::CurrentlyNoMatch(_) => GobbletsError::CurrentlyNoMatch,
+            GameError::CannotPutPieceHere(_) => GobbletsError::CannotPutPieceHere,
+            GameError::SquareIsEmpty(_) => GobbletsError::SquareIsEmpty,
+            GameError::PieceNotAvailable(_) => GobbletsError::PieceNotAvailable,
+            GameError::NotYourPiece(_) => GobbletsError::NotYourPiece,
+            GameError::GameIsOver(_) => GobbletsError::GameIsOver,
+            GameError::InvalidPosition(_) => GobbletsError::InvalidPosition,
+            GameError::UnsupportedCommand(_) => GobbletsError::UnsupportedCommand,
+            GameError::UnknownError => GobbletsError::UnknownError,
+        }
+    }
+}
+
+impl From<Color> for GobbletsColor {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Red => GobbletsColor::Red,
+            Color::Blue => GobbletsColor::Blue,
+        }
+    }
+}
+
+impl From<PieceSize> for GobbletsPieceSize {
+    fn from(piece_size: PieceSize) -> Self {
+        match piece_size {
+            PieceSize::Small => GobbletsPieceSize::Small,
+            PieceSize::Medium => GobbletsPieceSize::Medium,
+            PieceSize::Big => GobbletsPieceSize::Big,
+        }
+    }
+}
+
+impl From<GobbletsPieceSize> for PieceSize {
+    fn from(piece_size: GobbletsPieceSize) -> Self {
+        match piece_size {
+            GobbletsPieceSize::Small => PieceSize::Small,
+            GobbletsPieceSize::Medium => PieceSize::Medium,
+            GobbletsPieceSize::Big => PieceSize::Big,
+        }
+    }
+}
+
+fn square_to_ffi(square: &Option<PieceState>) -> GobbletsSquare {
+    let mut stack = Vec::new();
+    let mut piece_state = square.as_ref();
+
+    while let Some(current_piece_state) = piece_state {
+        stack.push(GobbletsPiece {
+            color: current_piece_state.color.into(),
+            size: current_piece_state.size.into(),
+        });
+        piece_state = current_piece_state.nested_piece.as_deref();
+    }
+
+    let mut ffi_square = GobbletsSquare {
+        height: stack.len() as u32,
+        pieces: [GobbletsPiece {
+            color: GobbletsColor::Red,
+            size: GobbletsPieceSize::Small,
+        }; GOBBLETS_MAX_STACK_HEIGHT],
+    };
+    for (index, piece) in stack.into_iter().rev().enumerate() {
+        ffi_square.pieces[index] = piece;
+    }
+
+    ffi_square
+}
+
+fn player_to_ffi(player_state: &PlayerState) -> GobbletsPlayer {
+    let count = |piece_size| {
+        player_state
+            .pieces
+            .iter()
+            .filter(|&&size| size == piece_size)
+            .count() as u32
+    };
+
+    GobbletsPlayer {
+        color: player_state.color.into(),
+        small_pieces: count(PieceSize::Small),
+        medium_pieces: count(PieceSize::Medium),
+        big_pieces: count(PieceSize::Big),
+        remaining_time_ms: player_state
+            .remaining_time
+            .map_or(-1, |remaining_time| remaining_time.as_millis() as i64),
+    }
+}
+
+fn game_state_to_ffi(game_state: &GameState) -> GobbletsState {
+    GobbletsState {
+        squares: game_state
+            .board
+            .squares
+            .each_ref()
+            .map(|row| row.each_ref().map(square_to_ffi)),
+        players: game_state.players.each_ref().map(player_to_ffi),
+        turn: game_state.turn,
+        current_color: game_state.current_color.into(),
+        outcome: match game_state.winner_color {
+            Some(Color::Red) => GobbletsOutcome::RedWins,
+            Some(Color::Blue) => GobbletsOutcome::BlueWins,
+            None if game_state.draw => GobbletsOutcome::Draw,
+            None => GobbletsOutcome::InProgress,
+        },
+    }
+}
+
+fn to_error_code(result: Result<(), GameError>) -> GobbletsError {
+    match result {
+        Ok(()) => GobbletsError::Ok,
+        Err(game_error) => GobbletsError::from(&game_error),
+    }
+}
+
+fn is_on_board(coordinates: &[u32]) -> bool {
+    coordinates
+        .iter()
+        .all(|&coordinate| coordinate < BOARD_SIZE)
+}
+
+#[no_mangle]
+pub extern "C" fn gobblets_game_new() -> *mut GobbletsGame {
+    Box::into_raw(Box::new(GobbletsGame {
+        game_instance: GameInstance::default(),
+    }))
+}
+
+/// # Safety
+///
+/// `position` doit être une chaîne C valide terminée par un octet nul.
+#[no_mangle]
+pub unsafe extern "C" fn gobblets_game_from_position(position: *const c_char) -> *mut GobbletsGame {
+    if position.is_null() {
+        return ptr::null_mut();
+    }
+
+    match CStr::from_ptr(position)
+        .to_str()
+        .ok()
+        .and_then(|position| position.parse().ok())
+    {
+        Some(game_instance) => Box::into_raw(Box::new(GobbletsGame { game_instance })),
+        None => ptr::null_mut(),
+    }
+}
+
+/// # Safety
+///
+/// `game` doit être nul ou provenir de `gobblets_game_new`, et n'être libéré qu'une fois.
+#[no_mangle]
+pub unsafe extern "C" fn gobblets_game_free(game: *mut GobbletsGame) {
+    if !game.is_null() {
+        drop(Box::from_raw(game));
+    }
+}
+
+/// # Safety
+///
+/// `game` doit être nul ou pointer vers une partie valide.
+#[no_mangle]
+pub unsafe extern "C" fn gobblets_game_put_piece(
+    game: *mut GobbletsGame,
+    x: u32,
+    y: u32,
+    size: GobbletsPieceSize,
+) -> GobbletsError {
+    match game.as_mut() {
+        Some(game) if is_on_board(&[x, y]) => to_error_code(game.game_instance.put_piece(
+            x as usize,
+            y as usize,
+            size.into(),
+        )),
+        _ => GobbletsError::InvalidArgument,
+    }
+}
+
+/// # Safety
+///
+/// `game` doit être nul ou pointer vers une partie valide.
+#[no_mangle]
+pub unsafe extern "C" fn gobblets_game_move_piece(
+    game: *mut GobbletsGame,
+    origin_x: u32,
+    origin_y: u32,
+    destination_x: u32,
+    destination_y: u32,
+) -> GobbletsError {
+    match game.as_mut() {
+        Some(game) if is_on_board(&[origin_x, origin_y, destination_x, destination_y]) => {
+            to_error_code(game.game_instance.move_piece(
+                origin_x as usize,
+                origin_y as usize,
+                destination_x as usize,
+                destination_y as usize,
+            ))
+        }
+        _ => GobbletsError::InvalidArgument,
+    }
+}
+
+/// # Safety
+///
+/// `game` doit être nul ou pointer vers une partie valide, et `state` être nul ou
+/// pointer vers une zone inscriptible de taille suffisante.
+#[no_mangle]
+pub unsafe extern "C" fn gobblets_game_get_state(
+    game: *const GobbletsGame,
+    state: *mut GobbletsState,
+) -> GobbletsError {
+    match (game.as_ref(), state.is_null()) {
+        (Some(game), false) => {
+            state.write(game_state_to_ffi(&game.game_instance.to_game_state()));
+            GobbletsError::Ok
+        }
+        _ => GobbletsError::InvalidArgument,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ffi::ffi_game::{
+        gobblets_game_free, gobblets_game_from_position, gobblets_game_get_state,
+        gobblets_game_move_piece, gobblets_game_new, gobblets_game_put_piece, GobbletsColor,
+        GobbletsError, GobbletsOutcome, GobbletsPieceSize, GobbletsState,
+        GOBBLETS_MAX_STACK_HEIGHT,
+    };
+    use std::ffi::CString;
+    use std::mem::MaybeUninit;
+    use std::ptr;
+
+    unsafe fn get_state(game: *const super::GobbletsGame) -> GobbletsState {
+        let mut state = MaybeUninit::<GobbletsState>::uninit();
+        assert_eq!(
+            gobblets_game_get_state(game, state.as_mut_ptr()),
+            GobbletsError::Ok
+        );
+        state.assume_init()
+    }
+
+    #[test]
+    fn play_test() {
+        unsafe {
+            let game = gobblets_game_new();
+
+            assert_eq!(
+                gobblets_game_put_piece(game, 1, 1, GobbletsPieceSize::Small),
+                GobbletsError::Ok
+            );
+            assert_eq!(
+                gobblets_game_put_piece(game, 1, 1, GobbletsPieceSize::Medium),
+                GobbletsError::Ok
+            );
+            assert_eq!(
+                gobblets_game_move_piece(game, 1, 1, 0, 0),
+                GobbletsError::NotYourPiece
+            );
+            assert_eq!(
+                gobblets_game_put_piece(game, 3, 0, GobbletsPieceSize::Big),
+                GobbletsError::InvalidArgument
+            );
+
+            let state = get_state(game);
+            let square = state.squares[1][1];
+            assert_eq!(square.height, 2);
+            assert_eq!(square.pieces[0].color, GobbletsColor::Red);
+            assert_eq!(square.pieces[1].size, GobbletsPieceSize::Medium);
+            assert_eq!(state.players[0].small_pieces, 1);
+            assert_eq!(state.players[1].medium_pieces, 1);
+            assert_eq!(state.players[0].remaining_time_ms, -1);
+            assert_eq!(state.current_color, GobbletsColor::Red);
+            assert_eq!(state.outcome, GobbletsOutcome::InProgress);
+
+            gobblets_game_free(game);
+        }
+    }
+
+    #[test]
+    fn full_stack_test() {
+        unsafe {
+            let position = CString::new("-,-,-/-,SmB,-/-,-,- SMMB ssmbb b 3").unwrap();
+            let game = gobblets_game_from_position(position.as_ptr());
+
+            let square = get_state(game).squares[1][1];
+            assert_eq!(square.height as usize, GOBBLETS_MAX_STACK_HEIGHT);
+            assert_eq!(square.pieces[2].size, GobbletsPieceSize::Big);
+
+            gobblets_game_free(game);
+        }
+    }
+
+    #[test]
+    fn from_position_test() {
+        unsafe {
+            let position = CString::new("B,-,-/s,B,-/-,-,M SSM smmbb b 4").unwrap();
+            let game = gobblets_game_from_position(position.as_ptr());
+            assert!(!game.is_null());
+            assert_eq!(get_state(game).outcome, GobbletsOutcome::RedWins);
+            assert_eq!(
+                gobblets_game_put_piece(game, 0, 1, GobbletsPieceSize::Small),
+                GobbletsError::GameIsOver
+            );
+            gobblets_game_free(game);
+
+            let position = CString::new("invalide").unwrap();
+            assert!(gobblets_game_from_position(position.as_ptr()).is_null());
+            assert!(gobblets_game_from_position(ptr::null()).is_null());
+            assert_eq!(
+                gobblets_game_get_state(ptr::null(), ptr::null_mut()),
+                GobbletsError::InvalidArgument
+            );
+        }
+    }
+}
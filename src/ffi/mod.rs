@@ -0,0 +1 @@
+pub mod ffi_game;
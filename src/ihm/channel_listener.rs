@@ -7,15 +7,13 @@ pub struct ChannelListener {
 }
 
 impl ChannelListener {
-    pub(crate) fn new(tx: Sender<GameEvent>) -> ChannelListener {
+    pub fn new(tx: Sender<GameEvent>) -> ChannelListener {
         ChannelListener { tx }
     }
 }
 
 impl Listener for ChannelListener {
     fn notify(&self, game_event: GameEvent) {
-        self.tx
-            .send(game_event)
-            .expect("Impossible de transmettre l'event");
+        let _ = self.tx.send(game_event);
     }
 }
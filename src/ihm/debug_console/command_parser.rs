@@ -0,0 +1,166 @@
+use crate::model::board::BOARD_SIZE;
+use crate::model::clock::TimeControl;
+use crate::model::game_command::GameCommand;
+use crate::model::game_state::GameState;
+use crate::model::piece_size::PieceSize;
+use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+use std::time::Duration;
+
+fn piece_size_from_number(i: &str) -> Result<PieceSize, String> {
+    match i {
+        "1" => Ok(Small),
+        "2" => Ok(Medium),
+        "3" => Ok(Big),
+        _ => Err(format!("Taille de pièce inconnue : {}", i)),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(value: Option<&str>) -> Result<T, String> {
+    let value = value.ok_or_else(|| String::from("Argument manquant"))?;
+    value
+        .parse()
+        .map_err(|_| format!("Nombre invalide : {}", value))
+}
+
+fn parse_coordinate(value: Option<&str>) -> Result<usize, String> {
+    let coordinate = parse_number(value)?;
+
+    if coordinate < BOARD_SIZE {
+        Ok(coordinate)
+    } else {
+        Err(format!(
+            "Coordonnée hors du plateau : {} (attendu 0 à {})",
+            coordinate,
+            BOARD_SIZE - 1
+        ))
+    }
+}
+
+fn time_control_from_arguments(arguments: &[&str]) -> Result<TimeControl, String> {
+    let base_time = parse_number(arguments.first().copied())?;
+    let increment = match arguments.get(1) {
+        Some(increment) => parse_number(Some(increment))?,
+        None => 0,
+    };
+
+    Ok(TimeControl::new(
+        Duration::from_secs(base_time),
+        Duration::from_secs(increment),
+    ))
+}
+
+pub fn parse_command(input: &str) -> Result<GameCommand, String> {
+    let input = input.trim();
+    let arguments: Vec<&str> = input.split_whitespace().skip(1).collect();
+    let argument = |index: usize| arguments.get(index).copied();
+
+    match input.split_whitespace().next() {
+        Some("1") => Ok(GameCommand::NewGameCommand),
+        Some("2") => Ok(GameCommand::PutPieceCommand(
+            parse_coordinate(argument(0))?,
+            parse_coordinate(argument(1))?,
+            piece_size_from_number(argument(2).unwrap_or_default())?,
+        )),
+        Some("3") => Ok(GameCommand::MovePieceCommand(
+            parse_coordinate(argument(0))?,
+            parse_coordinate(argument(1))?,
+            parse_coordinate(argument(2))?,
+            parse_coordinate(argument(3))?,
+        )),
+        Some("4") => Ok(GameCommand::ExitCommand),
+        Some("5") => Ok(GameCommand::NewTimedGameCommand(
+            time_control_from_arguments(&arguments)?,
+        )),
+        Some("6") => Ok(GameCommand::HintCommand),
+        Some("7") => Ok(GameCommand::AnalyzeCommand),
+        Some("8") => input[1..]
+            .trim()
+            .parse::<GameState>()
+            .map(|game_state| GameCommand::SetupCommand(Box::new(game_state)))
+            .map_err(|error| format!("Position invalide : {:?}", error)),
+        Some("p") => Ok(GameCommand::SnapshotCommand),
+        Some("9") => match argument(0) {
+            Some(games_count) => Ok(GameCommand::NewMatchCommand(parse_number(Some(
+                games_count,
+            ))?)),
+            None => Ok(GameCommand::RematchCommand),
+        },
+        _ => Err(format!("Commande inconnue : {}", input)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ihm::debug_console::command_parser::parse_command;
+    use crate::model::game_command::GameCommand;
+    use crate::model::piece_size::PieceSize::{Big, Medium};
+    use std::time::Duration;
+
+    #[test]
+    fn parse_command_test() {
+        assert!(matches!(
+            parse_command("1"),
+            Ok(GameCommand::NewGameCommand)
+        ));
+        assert!(matches!(
+            parse_command("2 1 2 3\n"),
+            Ok(GameCommand::PutPieceCommand(1, 2, Big))
+        ));
+        assert!(matches!(
+            parse_command("3 0 0 2 2"),
+            Ok(GameCommand::MovePieceCommand(0, 0, 2, 2))
+        ));
+        assert!(matches!(
+            parse_command("5 300"),
+            Ok(GameCommand::NewTimedGameCommand(time_control))
+                if time_control.base_time == Duration::from_secs(300)
+                    && time_control.increment.is_zero()
+        ));
+        assert!(matches!(
+            parse_command("7 analyse.txt"),
+            Ok(GameCommand::AnalyzeCommand)
+        ));
+        assert!(matches!(
+            parse_command("8 -,-,-/-,M,-/-,-,- SSMBB ssmmbb b 1"),
+            Ok(GameCommand::SetupCommand(game_state)) if game_state.board.squares[1][1].as_ref().unwrap().size == Medium
+        ));
+        assert!(matches!(
+            parse_command("9 3"),
+            Ok(GameCommand::NewMatchCommand(3))
+        ));
+        assert!(matches!(
+            parse_command("9"),
+            Ok(GameCommand::RematchCommand)
+        ));
+        assert!(matches!(
+            parse_command("p"),
+            Ok(GameCommand::SnapshotCommand)
+        ));
+    }
+
+    #[test]
+    fn parse_invalid_command_test() {
+        assert_eq!(
+            parse_command("2 1 2 4").err().unwrap(),
+            "Taille de pièce inconnue : 4"
+        );
+        assert_eq!(parse_command("3 0 0 2").err().unwrap(), "Argument manquant");
+        assert_eq!(
+            parse_command("2 5 0 1").err().unwrap(),
+            "Coordonnée hors du plateau : 5 (attendu 0 à 2)"
+        );
+        assert!(parse_command("3 0 0 2 3").is_err());
+        assert_eq!(parse_command("5 x").err().unwrap(), "Nombre invalide : x");
+        assert_eq!(parse_command("5").err().unwrap(), "Argument manquant");
+        assert_eq!(
+            parse_command("5 300 -2").err().unwrap(),
+            "Nombre invalide : -2"
+        );
+        assert!(parse_command("8 invalide").is_err());
+        assert_eq!(
+            parse_command("bonjour").err().unwrap(),
+            "Commande inconnue : bonjour"
+        );
+        assert!(parse_command("").is_err());
+    }
+}
@@ -1,88 +1,217 @@
 use crate::ihm::channel_listener::ChannelListener;
+use crate::ihm::debug_console::command_parser::parse_command;
+use crate::ihm::svg_diagram::{export_diagrams, game_state_to_svg, SvgOptions};
 use crate::model::game_command::GameCommand;
+use crate::model::game_error::GameError::CurrentlyNoGame;
 use crate::model::game_event::GameEvent;
-use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+use crate::model::game_state::GameState;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
 use std::sync::mpsc::{Receiver, Sender};
-use std::sync::{mpsc, Arc};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread::JoinHandle;
 use std::{io, thread};
-use crate::model::piece_size::PieceSize;
 
 pub struct DebugConsole {
     rx_event: Option<Receiver<GameEvent>>,
     listener: Arc<ChannelListener>,
     tx_command: Sender<GameCommand>,
+    handles: Vec<JoinHandle<()>>,
 }
 
-fn piece_size_from_number(i: &str) -> Result<PieceSize, ()> {
-    match i {
-        "1" => Ok(Small),
-        "2" => Ok(Medium),
-        "3" => Ok(Big),
-        _ => Err(()),
-    }
-
-
+fn export_game_diagrams(game_states: &[GameState], directory: &Path) {
+    let diagrams: Vec<String> = game_states
+        .iter()
+        .map(|game_state| game_state_to_svg(game_state, &SvgOptions::default()))
+        .collect();
 
+    match export_diagrams(&diagrams, directory) {
+        Ok(paths) => println!(
+            "{} diagramme(s) exporté(s) dans {}",
+            paths.len(),
+            directory.display()
+        ),
+        Err(error) => println!("Impossible d'exporter les diagrammes : {}", error),
+    }
 }
 
 impl DebugConsole {
-    pub(crate) fn new(tx_command: Sender<GameCommand>) -> Self {
+    pub fn new(tx_command: Sender<GameCommand>) -> Self {
         let (tx, rx) = mpsc::channel();
 
         DebugConsole {
             rx_event: Some(rx),
             listener: Arc::from(ChannelListener::new(tx)),
             tx_command,
+            handles: Vec::new(),
         }
     }
 
     pub fn get_channel_listener(&self) -> Arc<ChannelListener> {
-        return Arc::clone(&self.listener);
+        Arc::clone(&self.listener)
     }
 
     pub fn start(&mut self) {
+        self.start_with_input(BufReader::new(io::stdin()));
+    }
+
+    pub fn start_with_input<R: BufRead + Send + 'static>(&mut self, input: R) {
         let tx_command = self.tx_command.clone();
-        thread::spawn(move || loop {
-            let mut input = String::new();
-
-            io::stdin()
-                .read_line(&mut input)
-                .expect("Échec de la lecture de l'entrée utilisateur");
-
-            match &input.as_str()[0..1] {
-                "1" => tx_command
-                    .send(GameCommand::NewGameCommand)
-                    .expect("Erreur lors de l'envoie de la commande"),
-                "2" => tx_command
-                    .send(GameCommand::PutPieceCommand(
-                        input[2..3].parse().unwrap(),
-                        input[4..5].parse().unwrap(),
-                        piece_size_from_number(&input[6..7]).expect("Impossible de convertir"),
-                    ))
-                    .expect("Erreur lors de l'envoie de la commande"),
-                "3" => tx_command
-                    .send(GameCommand::MovePieceCommand(
-                        input[2..3].parse().unwrap(),
-                        input[4..5].parse().unwrap(),
-                        input[6..7].parse().unwrap(),
-                        input[8..9].parse().unwrap(),
-                    ))
-                    .expect("Erreur lors de l'envoie de la commande"),
-                "4" => tx_command
-                    .send(GameCommand::ExitCommand)
-                    .expect("Erreur lors de l'envoie de la commande"),
-                _ => {
-                    println!("Commande inconnue : {}", &input)
+        let analysis_export_path: Arc<Mutex<Option<PathBuf>>> = Arc::new(Mutex::new(None));
+        let input_analysis_export_path = Arc::clone(&analysis_export_path);
+        let game_states: Arc<Mutex<Vec<GameState>>> = Arc::new(Mutex::new(Vec::new()));
+        let input_game_states = Arc::clone(&game_states);
+        self.handles.push(thread::spawn(move || {
+            for input in input.lines() {
+                let input = input.expect("Échec de la lecture de l'entrée utilisateur");
+
+                let mut arguments = input.split_whitespace();
+                let game_command = match arguments.next() {
+                    Some("0") => {
+                        match arguments.next() {
+                            Some(directory) => export_game_diagrams(
+                                &input_game_states.lock().unwrap(),
+                                Path::new(directory),
+                            ),
+                            None => println!("Usage : 0 <dossier>"),
+                        }
+                        continue;
+                    }
+                    Some("7") => {
+                        *input_analysis_export_path.lock().unwrap() =
+                            arguments.next().map(PathBuf::from);
+                        GameCommand::AnalyzeCommand
+                    }
+                    _ => match parse_command(&input) {
+                        Ok(game_command) => game_command,
+                        Err(error) => {
+                            println!("{}", error);
+                            continue;
+                        }
+                    },
+                };
+
+                let exit = matches!(game_command, GameCommand::ExitCommand);
+                if tx_command.send(game_command).is_err() || exit {
+                    return;
                 }
-            };
-        });
+            }
+
+            let _ = tx_command.send(GameCommand::ExitCommand);
+        }));
 
         let rx_event = self.rx_event.take().unwrap();
-        thread::spawn(move || loop {
-            match rx_event.recv() {
-                Ok(game_event) => println!("{:#?}", game_event),
-                Err(_) => {}
+        let tx_command = self.tx_command.clone();
+        self.handles.push(thread::spawn(move || {
+            let mut out_of_sync = false;
+            for game_event in rx_event {
+                match game_event {
+                    GameEvent::AnalysisEvent(report) => {
+                        println!("{}", report);
+                        if let Some(path) = analysis_export_path.lock().unwrap().take() {
+                            match report.export(&path) {
+                                Ok(()) => println!("Analyse exportée dans {}", path.display()),
+                                Err(error) => {
+                                    println!("Impossible d'exporter l'analyse : {}", error)
+                                }
+                            }
+                        }
+                    }
+                    GameEvent::MatchUpdateEvent(score) | GameEvent::MatchEndEvent(score) => {
+                        println!("{}", score)
+                    }
+                    game_event => {
+                        match &game_event {
+                            GameEvent::NewGameEvent(game_state) => {
+                                *game_states.lock().unwrap() = vec![game_state.clone()];
+                                out_of_sync = false;
+                            }
+                            GameEvent::BoardUpdateEvent(game_state) if out_of_sync => {
+                                game_states.lock().unwrap().push(game_state.clone());
+                                out_of_sync = false;
+                            }
+                            GameEvent::MoveAppliedEvent(applied_move) => {
+                                let mut game_states = game_states.lock().unwrap();
+                                let result = match game_states.last().cloned() {
+                                    Some(mut game_state) => game_state
+                                        .apply_move(applied_move)
+                                        .map(|_| game_state),
+                                    None => Err(CurrentlyNoGame(String::from(
+                                        "Aucun état de partie connu pour appliquer le coup",
+                                    ))),
+                                };
+
+                                match result {
+                                    Ok(game_state) => game_states.push(game_state),
+                                    Err(game_error) => {
+                                        eprintln!(
+                                            "Impossible d'appliquer le coup ({}), resynchronisation : {}",
+                                            applied_move, game_error
+                                        );
+                                        out_of_sync = true;
+                                        let _ = tx_command.send(GameCommand::SnapshotCommand);
+                                    }
+                                }
+                            }
+                            _ => {}
+                        }
+                        println!("{:#?}", game_event);
+
+                        if matches!(game_event, GameEvent::ExitEvent) {
+                            return;
+                        }
+                    }
+                }
             }
+        }));
+    }
+
+    pub fn join(&mut self) {
+        for handle in self.handles.drain(..) {
+            handle.join().expect("Un thread de la console a paniqué");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ihm::debug_console::debug_console::DebugConsole;
+    use crate::ihm::game_thread::spawn_game_thread;
+    use crate::model::game_match::Match;
+    use std::io::Cursor;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    fn run_console(input: &'static str) {
+        let (tx_done, rx_done) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (tx_command, rx_command) = mpsc::channel();
+            let mut debug_console = DebugConsole::new(tx_command);
+            let mut game_match = Match::default();
+            game_match.subscribe(debug_console.get_channel_listener());
+
+            let game_handle = spawn_game_thread(game_match, rx_command);
+            debug_console.start_with_input(Cursor::new(input));
+
+            game_handle.join().unwrap();
+            debug_console.join();
+            tx_done.send(()).unwrap();
         });
+
+        rx_done
+            .recv_timeout(Duration::from_secs(5))
+            .expect("Un thread est toujours en cours d'exécution");
+    }
+
+    #[test]
+    fn exit_command_stops_all_threads_test() {
+        run_console("1\n2 1 1 3\n4\n2 0 0 1\n");
+    }
+
+    #[test]
+    fn end_of_input_stops_all_threads_test() {
+        run_console("1\ninconnue\n");
     }
 }
@@ -0,0 +1,58 @@
+use crate::model::game_event::GameEvent;
+
+pub fn render_event(game_event: &GameEvent) -> String {
+    match game_event {
+        GameEvent::NewGameEvent(game_state) => format!("Nouvelle partie : {}", game_state),
+        GameEvent::BoardUpdateEvent(game_state) => match game_state.winner_color {
+            Some(winner_color) => {
+                format!("Position : {} — victoire : {}", game_state, winner_color)
+            }
+            None if game_state.draw => format!("Position : {} — partie nulle", game_state),
+            None => format!("Position : {}", game_state),
+        },
+        GameEvent::MoveAppliedEvent(applied_move) => match applied_move.winner_color {
+            Some(winner_color) => format!("Coup : {} — victoire : {}", applied_move, winner_color),
+            None if applied_move.draw => format!("Coup : {} — partie nulle", applied_move),
+            None => format!("Coup : {}", applied_move),
+        },
+        GameEvent::GameWinEvent(color) => format!("Victoire : {}", color),
+        GameEvent::GameTimeoutEvent(loser) => format!("Temps écoulé pour {}", loser),
+        GameEvent::GameForfeitEvent(loser) => format!("Forfait de {}", loser),
+        GameEvent::GameErrorEvent(game_error) => format!("Erreur : {}", game_error),
+        GameEvent::HintEvent(game_move, evaluation) => {
+            format!("Conseil : {} ({})", game_move, evaluation)
+        }
+        GameEvent::AnalysisEvent(report) => report.to_string(),
+        GameEvent::MatchUpdateEvent(score) | GameEvent::MatchEndEvent(score) => score.to_string(),
+        GameEvent::ExitEvent => String::from("Fin de la session"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ihm::debug_console::event_renderer::render_event;
+    use crate::model::game_error::GameError::NotYourPiece;
+    use crate::model::game_event::GameEvent::{BoardUpdateEvent, GameErrorEvent, NewGameEvent};
+    use crate::model::game_instance::GameInstance;
+    use crate::model::piece_size::PieceSize::Big;
+
+    #[test]
+    fn render_event_test() {
+        let mut game_instance = GameInstance::default();
+        assert_eq!(
+            render_event(&NewGameEvent(game_instance.to_game_state())),
+            "Nouvelle partie : -,-,-/-,-,-/-,-,- SSMMBB ssmmbb r 0"
+        );
+
+        game_instance.put_piece(0, 0, Big).unwrap();
+        assert_eq!(
+            render_event(&BoardUpdateEvent(game_instance.to_game_state())),
+            "Position : B,-,-/-,-,-/-,-,- SSMMB ssmmbb b 1"
+        );
+
+        assert_eq!(
+            render_event(&GameErrorEvent(NotYourPiece(String::from("Pas à vous")))),
+            "Erreur : Pas à vous"
+        );
+    }
+}
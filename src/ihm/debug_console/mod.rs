@@ -1 +1,5 @@
+pub mod command_parser;
+#[allow(clippy::module_inception)]
 pub mod debug_console;
+pub mod event_renderer;
+pub mod script_runner;
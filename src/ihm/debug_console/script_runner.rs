@@ -0,0 +1,146 @@
+use crate::ihm::channel_listener::ChannelListener;
+use crate::ihm::debug_console::command_parser::parse_command;
+use crate::ihm::debug_console::event_renderer::render_event;
+use crate::model::game_command::GameCommand;
+use crate::model::game_event::GameEvent;
+use crate::model::game_match::Match;
+use std::io;
+use std::io::{BufRead, Write};
+use std::sync::{mpsc, Arc};
+use std::thread;
+
+pub struct ScriptRunner {
+    stop_on_error: bool,
+}
+
+impl ScriptRunner {
+    pub fn new(stop_on_error: bool) -> ScriptRunner {
+        ScriptRunner { stop_on_error }
+    }
+
+    pub fn run<R: BufRead, W: Write>(
+        &self,
+        script: R,
+        mut game_match: Match,
+        output: &mut W,
+    ) -> io::Result<bool> {
+        let (tx_command, rx_command) = mpsc::channel::<GameCommand>();
+        let (tx_event, rx_event) = mpsc::channel();
+        let (tx_executed, rx_executed) = mpsc::channel();
+
+        game_match.subscribe(Arc::new(ChannelListener::new(tx_event)));
+
+        let game_handle = thread::spawn(move || {
+            for game_command in rx_command {
+                let exit = game_match.execute(game_command);
+                if tx_executed.send(()).is_err() || exit {
+                    break;
+                }
+            }
+        });
+
+        let mut success = true;
+        for (index, line) in script.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            writeln!(output, "> {}", line)?;
+
+            let game_command = match parse_command(line) {
+                Ok(game_command) => game_command,
+                Err(error) => {
+                    writeln!(output, "Ligne {} : {}", index + 1, error)?;
+                    success = false;
+                    if self.stop_on_error {
+                        break;
+                    }
+                    continue;
+                }
+            };
+            let exit = matches!(game_command, GameCommand::ExitCommand);
+
+            if tx_command.send(game_command).is_err() || rx_executed.recv().is_err() {
+                writeln!(output, "Ligne {} : la partie est terminée", index + 1)?;
+                success = false;
+                break;
+            }
+
+            let mut command_failed = false;
+            for game_event in rx_event.try_iter() {
+                if matches!(game_event, GameEvent::MoveAppliedEvent(_)) {
+                    continue;
+                }
+                command_failed |= matches!(game_event, GameEvent::GameErrorEvent(_));
+                writeln!(output, "{}", render_event(&game_event))?;
+            }
+
+            if command_failed {
+                success = false;
+                if self.stop_on_error {
+                    writeln!(output, "Arrêt du script à la ligne {}", index + 1)?;
+                    break;
+                }
+            }
+
+            if exit {
+                break;
+            }
+        }
+
+        drop(tx_command);
+        game_handle
+            .join()
+            .map_err(|_| io::Error::other("Le thread de jeu a paniqué"))?;
+
+        Ok(success)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ihm::debug_console::script_runner::ScriptRunner;
+    use crate::model::game_match::Match;
+
+    fn run_script(script: &str, stop_on_error: bool) -> (bool, String) {
+        let mut output = Vec::new();
+        let success = ScriptRunner::new(stop_on_error)
+            .run(script.as_bytes(), Match::default(), &mut output)
+            .unwrap();
+
+        (success, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn successful_script_test() {
+        let (success, output) = run_script(
+            "# Victoire rouge\n1\n2 0 0 3\n2 1 0 1\n\n2 1 1 3\n2 2 0 1\n2 2 2 2\n4\n",
+            true,
+        );
+
+        assert!(success);
+        assert!(output.starts_with(
+            "> 1\nNouvelle partie : -,-,-/-,-,-/-,-,- SSMMBB ssmmbb r 0\n> 2 0 0 3\n"
+        ));
+        assert!(output.ends_with("SSM mmbb b 5 — victoire : Rouge\n> 4\nFin de la session\n"));
+    }
+
+    #[test]
+    fn failing_script_test() {
+        let script = "1\n2 0 0 3\n3 0 0 1 1\ninconnue\n2 1 1 1\n";
+
+        let (success, output) = run_script(script, false);
+        assert!(!success);
+        assert!(output
+            .contains("Erreur : Vous ne pouvez pas bouger une pièce qui ne vous appartient pas\n"));
+        assert!(output.contains("Ligne 4 : Commande inconnue : inconnue\n"));
+        assert!(output.ends_with("> 2 1 1 1\nPosition : B,-,-/-,s,-/-,-,- SSMMB smmbb r 2\n"));
+
+        let (success, output) = run_script(script, true);
+        assert!(!success);
+        assert!(output.ends_with("Arrêt du script à la ligne 3\n"));
+        assert!(!output.contains("inconnue"));
+    }
+}
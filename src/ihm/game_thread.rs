@@ -0,0 +1,54 @@
+use crate::model::clock::CLOCK_CHECK_INTERVAL;
+use crate::model::game_command::GameCommand;
+use crate::model::game_match::Match;
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::thread;
+use std::thread::JoinHandle;
+
+pub fn spawn_game_thread(
+    mut game_match: Match,
+    rx_command: Receiver<GameCommand>,
+) -> JoinHandle<()> {
+    thread::spawn(move || loop {
+        match rx_command.recv_timeout(CLOCK_CHECK_INTERVAL) {
+            Ok(game_command) => {
+                if game_match.execute(game_command) {
+                    break;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => game_match.check_clock(),
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ihm::game_thread::spawn_game_thread;
+    use crate::model::game_command::GameCommand::{ExitCommand, NewGameCommand};
+    use crate::model::game_match::Match;
+    use std::sync::mpsc;
+
+    #[test]
+    fn game_thread_stops_on_exit_test() {
+        let (tx_command, rx_command) = mpsc::channel();
+        let game_handle = spawn_game_thread(Match::default(), rx_command);
+
+        tx_command.send(NewGameCommand).unwrap();
+        tx_command.send(ExitCommand).unwrap();
+
+        game_handle.join().unwrap();
+        assert!(tx_command.send(NewGameCommand).is_err());
+    }
+
+    #[test]
+    fn game_thread_stops_on_disconnect_test() {
+        let (tx_command, rx_command) = mpsc::channel();
+        let game_handle = spawn_game_thread(Match::default(), rx_command);
+
+        tx_command.send(NewGameCommand).unwrap();
+        drop(tx_command);
+
+        game_handle.join().unwrap();
+    }
+}
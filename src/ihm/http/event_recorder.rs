@@ -0,0 +1,122 @@
+use crate::model::game_error::GameError::CurrentlyNoGame;
+use crate::model::game_event::GameEvent;
+use crate::model::game_state::GameState;
+use crate::model::listener::Listener;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+#[derive(Default)]
+pub struct EventRecorder {
+    events: Mutex<Vec<GameEvent>>,
+    game_state: Mutex<Option<GameState>>,
+    out_of_sync: AtomicBool,
+}
+
+impl EventRecorder {
+    pub fn take_events(&self) -> Vec<GameEvent> {
+        std::mem::take(&mut *self.events.lock().unwrap())
+    }
+
+    pub fn get_game_state(&self) -> Option<GameState> {
+        self.game_state.lock().unwrap().clone()
+    }
+
+    pub fn take_out_of_sync(&self) -> bool {
+        self.out_of_sync.swap(false, Ordering::SeqCst)
+    }
+}
+
+impl Listener for EventRecorder {
+    fn notify(&self, game_event: GameEvent) {
+        match &game_event {
+            GameEvent::NewGameEvent(game_state) | GameEvent::BoardUpdateEvent(game_state) => {
+                *self.game_state.lock().unwrap() = Some(game_state.clone());
+                self.out_of_sync.store(false, Ordering::SeqCst);
+            }
+            GameEvent::MoveAppliedEvent(applied_move) => {
+                let result = match self.game_state.lock().unwrap().as_mut() {
+                    Some(game_state) => game_state.apply_move(applied_move),
+                    None => Err(CurrentlyNoGame(String::from(
+                        "Aucun état de partie connu pour appliquer le coup",
+                    ))),
+                };
+
+                if let Err(game_error) = result {
+                    eprintln!(
+                        "Impossible d'appliquer le coup ({}), resynchronisation : {}",
+                        applied_move, game_error
+                    );
+                    self.out_of_sync.store(true, Ordering::SeqCst);
+                }
+            }
+            GameEvent::GameTimeoutEvent(loser) | GameEvent::GameForfeitEvent(loser) => {
+                if let Some(game_state) = self.game_state.lock().unwrap().as_mut() {
+                    game_state.winner_color = Some(loser.opponent());
+                }
+            }
+            _ => {}
+        }
+
+        self.events.lock().unwrap().push(game_event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ihm::http::event_recorder::EventRecorder;
+    use crate::model::game_event::GameEvent::{
+        BoardUpdateEvent, ExitEvent, GameTimeoutEvent, MoveAppliedEvent, NewGameEvent,
+    };
+    use crate::model::game_instance::GameInstance;
+    use crate::model::game_move::Move;
+    use crate::model::listener::Listener;
+    use crate::model::piece_size::PieceSize::{Big, Small};
+    use crate::model::player::Color::{Blue, Red};
+
+    #[test]
+    fn record_events_test() {
+        let event_recorder = EventRecorder::default();
+
+        assert!(event_recorder.get_game_state().is_none());
+
+        event_recorder.notify(NewGameEvent(GameInstance::default().to_game_state()));
+        event_recorder.notify(GameTimeoutEvent(Red));
+        event_recorder.notify(ExitEvent);
+
+        assert_eq!(event_recorder.take_events().len(), 3);
+        assert!(event_recorder.take_events().is_empty());
+        assert_eq!(
+            event_recorder.get_game_state().unwrap().winner_color,
+            Some(Blue)
+        );
+    }
+
+    #[test]
+    fn out_of_sync_move_test() {
+        let event_recorder = EventRecorder::default();
+        let mut game_instance = GameInstance::default();
+        let applied_move = game_instance.apply_move(Move::PutPiece(0, 0, Big)).unwrap();
+
+        event_recorder.notify(MoveAppliedEvent(applied_move));
+        assert!(event_recorder.take_out_of_sync());
+        assert!(!event_recorder.take_out_of_sync());
+
+        event_recorder.notify(NewGameEvent(GameInstance::default().to_game_state()));
+        game_instance.put_piece(1, 1, Small).unwrap();
+        event_recorder.notify(MoveAppliedEvent(
+            game_instance
+                .apply_move(Move::MovePiece(0, 0, 2, 2))
+                .unwrap(),
+        ));
+        assert!(event_recorder.take_out_of_sync());
+
+        event_recorder.notify(MoveAppliedEvent(
+            game_instance
+                .apply_move(Move::PutPiece(0, 0, Small))
+                .unwrap(),
+        ));
+        event_recorder.notify(BoardUpdateEvent(game_instance.to_game_state()));
+        assert!(!event_recorder.take_out_of_sync());
+        assert_eq!(event_recorder.get_game_state().unwrap().turn, 4);
+    }
+}
@@ -0,0 +1,156 @@
+use std::io;
+use std::io::{BufRead, Write};
+
+const MAX_BODY_LENGTH: usize = 64 * 1024;
+
+#[derive(Debug)]
+pub struct HttpRequest {
+    pub method: String,
+    pub path: String,
+    pub body: String,
+}
+
+#[derive(Debug)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+impl HttpRequest {
+    pub fn read_from<R: BufRead>(reader: &mut R) -> io::Result<HttpRequest> {
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+
+        let mut request_line = request_line.split_whitespace();
+        let (method, target) = match (request_line.next(), request_line.next()) {
+            (Some(method), Some(target)) => (method.to_string(), target),
+            _ => return Err(invalid_data("Ligne de requête HTTP invalide")),
+        };
+        let path = target.split('?').next().unwrap_or(target).to_string();
+
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            if reader.read_line(&mut header)? == 0 {
+                break;
+            }
+
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+
+            if let Some((name, value)) = header.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("content-length") {
+                    content_length = value
+                        .trim()
+                        .parse()
+                        .map_err(|_| invalid_data("En-tête Content-Length invalide"))?;
+                }
+            }
+        }
+
+        if content_length > MAX_BODY_LENGTH {
+            return Err(invalid_data("Corps de requête trop volumineux"));
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body)?;
+
+        Ok(HttpRequest {
+            method,
+            path,
+            body: String::from_utf8(body)
+                .map_err(|_| invalid_data("Le corps de la requête doit être en UTF-8"))?,
+        })
+    }
+}
+
+impl HttpResponse {
+    pub fn json(status: u16, body: String) -> HttpResponse {
+        HttpResponse {
+            status,
+            content_type: "application/json; charset=utf-8",
+            body,
+        }
+    }
+
+    pub fn html(body: &str) -> HttpResponse {
+        HttpResponse {
+            status: 200,
+            content_type: "text/html; charset=utf-8",
+            body: body.to_string(),
+        }
+    }
+
+    fn reason_phrase(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            409 => "Conflict",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        }
+    }
+
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(
+            writer,
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            self.reason_phrase(),
+            self.content_type,
+            self.body.len(),
+            self.body
+        )?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ihm::http::http_message::{HttpRequest, HttpResponse};
+    use std::io::BufReader;
+
+    #[test]
+    fn read_request_test() {
+        let raw_request = "POST /api/put?debug=1 HTTP/1.1\r\nHost: localhost\r\ncontent-length: 7\r\n\r\n{\"x\":1}";
+        let request = HttpRequest::read_from(&mut BufReader::new(raw_request.as_bytes())).unwrap();
+
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/api/put");
+        assert_eq!(request.body, "{\"x\":1}");
+    }
+
+    #[test]
+    fn read_invalid_request_test() {
+        for raw_request in [
+            "",
+            "GET\r\n\r\n",
+            "POST / HTTP/1.1\r\nContent-Length: abc\r\n\r\n",
+            "POST / HTTP/1.1\r\nContent-Length: 10\r\n\r\n{}",
+        ] {
+            assert!(HttpRequest::read_from(&mut BufReader::new(raw_request.as_bytes())).is_err());
+        }
+    }
+
+    #[test]
+    fn write_response_test() {
+        let mut output = Vec::new();
+        HttpResponse::json(404, String::from("{}"))
+            .write_to(&mut output)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "HTTP/1.1 404 Not Found\r\nContent-Type: application/json; charset=utf-8\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}"
+        );
+    }
+}
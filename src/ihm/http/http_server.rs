@@ -0,0 +1,768 @@
+use crate::ihm::http::event_recorder::EventRecorder;
+use crate::ihm::http::http_message::{HttpRequest, HttpResponse};
+use crate::ihm::http::spectator_broadcaster::{server_sent_event, SpectatorBroadcaster};
+use crate::ihm::json;
+use crate::ihm::json::JsonValue;
+use crate::ihm::svg_diagram::{game_state_to_svg, SvgOptions};
+use crate::model::board::BOARD_SIZE;
+use crate::model::clock::{TimeControl, CLOCK_CHECK_INTERVAL};
+use crate::model::game_command::GameCommand;
+use crate::model::game_error::GameError;
+use crate::model::game_event::GameEvent;
+use crate::model::game_match::Match;
+use crate::model::game_state::GameState;
+use std::io;
+use std::io::{BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{RecvTimeoutError, Sender};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+const INDEX_HTML: &str = include_str!("index.html");
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_CONNECTIONS: usize = 64;
+const MAX_SPECTATORS: usize = 256;
+
+struct GameRequest {
+    command: GameCommand,
+    tx_events: Sender<Vec<GameEvent>>,
+}
+
+#[derive(Clone)]
+pub struct HttpServer {
+    tx_request: Sender<GameRequest>,
+    event_recorder: Arc<EventRecorder>,
+    spectator_broadcaster: Arc<SpectatorBroadcaster>,
+    active_connections: Arc<AtomicUsize>,
+    max_connections: usize,
+    active_spectators: Arc<AtomicUsize>,
+    max_spectators: usize,
+}
+
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn reserve_slot(active: &Arc<AtomicUsize>, max: usize) -> Option<ConnectionSlot> {
+    let previously_active = active.fetch_add(1, Ordering::SeqCst);
+    let slot = ConnectionSlot(Arc::clone(active));
+
+    (previously_active < max).then_some(slot)
+}
+
+fn service_unavailable(message: &str) -> HttpResponse {
+    HttpResponse::json(503, json::error_to_json("ServiceUnavailable", message))
+}
+
+fn bad_request(message: &str) -> HttpResponse {
+    HttpResponse::json(400, json::error_to_json("BadRequest", message))
+}
+
+fn game_error_status(game_error: &GameError) -> u16 {
+    match game_error {
+        GameError::InvalidPosition(_) => 400,
+        GameError::UnsupportedCommand(_) => 501,
+        GameError::UnknownError => 500,
+        _ => 409,
+    }
+}
+
+fn find_game_error(events: &[GameEvent]) -> Option<&GameError> {
+    events.iter().find_map(|game_event| match game_event {
+        GameEvent::GameErrorEvent(game_error) => Some(game_error),
+        _ => None,
+    })
+}
+
+fn parse_body(body: &str) -> Result<Vec<(String, JsonValue)>, String> {
+    if body.trim().is_empty() {
+        Ok(Vec::new())
+    } else {
+        json::parse_object(body)
+    }
+}
+
+fn new_game_command(fields: &[(String, JsonValue)]) -> Result<GameCommand, String> {
+    if let Some(JsonValue::String(position)) = json::get_field(fields, "position") {
+        return match position.parse::<GameState>() {
+            Ok(game_state) => Ok(GameCommand::SetupCommand(Box::new(game_state))),
+            Err(GameError::InvalidPosition(message)) => Err(message),
+            Err(_) => Err(String::from("Position invalide")),
+        };
+    }
+
+    if json::get_field(fields, "base").is_none() {
+        return Ok(GameCommand::NewGameCommand);
+    }
+
+    let base_time = json::get_usize(fields, "base")? as u64;
+    let increment = match json::get_field(fields, "increment") {
+        Some(_) => json::get_usize(fields, "increment")? as u64,
+        None => 0,
+    };
+
+    Ok(GameCommand::NewTimedGameCommand(TimeControl::new(
+        Duration::from_secs(base_time),
+        Duration::from_secs(increment),
+    )))
+}
+
+fn new_match_command(fields: &[(String, JsonValue)]) -> Result<GameCommand, String> {
+    Ok(GameCommand::NewMatchCommand(
+        json::get_usize(fields, "games")? as u32,
+    ))
+}
+
+fn get_coordinate(fields: &[(String, JsonValue)], name: &str) -> Result<usize, String> {
+    let coordinate = json::get_usize(fields, name)?;
+
+    if coordinate < BOARD_SIZE {
+        Ok(coordinate)
+    } else {
+        Err(format!(
+            "Le champ \"{}\" doit être compris entre 0 et {}",
+            name,
+            BOARD_SIZE - 1
+        ))
+    }
+}
+
+fn put_piece_command(fields: &[(String, JsonValue)]) -> Result<GameCommand, String> {
+    let size = json::get_string(fields, "size")?;
+
+    Ok(GameCommand::PutPieceCommand(
+        get_coordinate(fields, "x")?,
+        get_coordinate(fields, "y")?,
+        json::piece_size_from_name(size)
+            .ok_or_else(|| format!("Taille de pièce inconnue \"{}\"", size))?,
+    ))
+}
+
+fn move_piece_command(fields: &[(String, JsonValue)]) -> Result<GameCommand, String> {
+    Ok(GameCommand::MovePieceCommand(
+        get_coordinate(fields, "origin_x")?,
+        get_coordinate(fields, "origin_y")?,
+        get_coordinate(fields, "destination_x")?,
+        get_coordinate(fields, "destination_y")?,
+    ))
+}
+
+impl HttpServer {
+    pub fn new(mut game_match: Match) -> HttpServer {
+        let (tx_request, rx_request) = mpsc::channel::<GameRequest>();
+        let event_recorder = Arc::new(EventRecorder::default());
+        let spectator_broadcaster = Arc::new(SpectatorBroadcaster::default());
+
+        game_match.subscribe(Arc::clone(&event_recorder) as _);
+        game_match.subscribe(Arc::clone(&spectator_broadcaster) as _);
+
+        let game_event_recorder = Arc::clone(&event_recorder);
+        thread::spawn(move || loop {
+            match rx_request.recv_timeout(CLOCK_CHECK_INTERVAL) {
+                Ok(game_request) => {
+                    game_event_recorder.take_events();
+                    let exit = game_match.execute(game_request.command);
+                    if game_event_recorder.take_out_of_sync() {
+                        game_match.execute(GameCommand::SnapshotCommand);
+                    }
+                    let _ = game_request
+                        .tx_events
+                        .send(game_event_recorder.take_events());
+
+                    if exit {
+                        break;
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => game_match.check_clock(),
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        });
+
+        HttpServer {
+            tx_request,
+            event_recorder,
+            spectator_broadcaster,
+            active_connections: Arc::new(AtomicUsize::new(0)),
+            max_connections: MAX_CONNECTIONS,
+            active_spectators: Arc::new(AtomicUsize::new(0)),
+            max_spectators: MAX_SPECTATORS,
+        }
+    }
+
+    pub fn with_max_connections(mut self, max_connections: usize) -> HttpServer {
+        self.max_connections = max_connections;
+        self
+    }
+
+    pub fn with_max_spectators(mut self, max_spectators: usize) -> HttpServer {
+        self.max_spectators = max_spectators;
+        self
+    }
+
+    pub fn serve(&self, address: &str) -> std::io::Result<()> {
+        let listener = TcpListener::bind(address)?;
+        println!("Serveur HTTP démarré sur http://{}", listener.local_addr()?);
+
+        self.accept_connections(listener);
+        Ok(())
+    }
+
+    fn accept_connections(&self, listener: TcpListener) {
+        for mut stream in listener.incoming().flatten() {
+            let connection_slot = match reserve_slot(&self.active_connections, self.max_connections)
+            {
+                Some(connection_slot) => connection_slot,
+                None => {
+                    let _ =
+                        service_unavailable("Trop de connexions simultanées").write_to(&mut stream);
+                    continue;
+                }
+            };
+
+            let http_server = self.clone();
+            thread::spawn(move || {
+                let _ = stream.set_read_timeout(Some(REQUEST_TIMEOUT));
+                http_server.handle_connection(stream, connection_slot);
+            });
+        }
+    }
+
+    fn handle_connection(&self, mut stream: TcpStream, connection_slot: ConnectionSlot) {
+        let response = match stream
+            .try_clone()
+            .and_then(|stream| HttpRequest::read_from(&mut BufReader::new(stream)))
+        {
+            Ok(request) if request.method == "GET" && request.path == "/api/events" => {
+                drop(connection_slot);
+                match reserve_slot(&self.active_spectators, self.max_spectators) {
+                    Some(_spectator_slot) => {
+                        let _ = self.stream_events(stream);
+                        return;
+                    }
+                    None => service_unavailable("Trop de spectateurs simultanés"),
+                }
+            }
+            Ok(request) => self.handle(&request),
+            Err(error) => bad_request(&error.to_string()),
+        };
+
+        if let Err(error) = response.write_to(&mut stream) {
+            eprintln!("Impossible d'envoyer la réponse HTTP : {}", error);
+        }
+        let _ = stream.flush();
+    }
+
+    fn stream_events(&self, mut stream: TcpStream) -> io::Result<()> {
+        let rx_spectator = self.spectator_broadcaster.add_spectator();
+        let snapshot = match self.event_recorder.get_game_state() {
+            Some(game_state) => json::game_state_to_json(&game_state),
+            None => String::from("null"),
+        };
+
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: keep-alive\r\n\r\n{}",
+            server_sent_event("snapshot", &snapshot)
+        )?;
+        stream.flush()?;
+
+        loop {
+            let message = match rx_spectator.recv_timeout(KEEP_ALIVE_INTERVAL) {
+                Ok(message) => message,
+                Err(RecvTimeoutError::Timeout) => String::from(": keep-alive\n\n"),
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            };
+
+            stream.write_all(message.as_bytes())?;
+            stream.flush()?;
+        }
+    }
+
+    pub fn handle(&self, request: &HttpRequest) -> HttpResponse {
+        let command = match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/") | ("GET", "/index.html") => return HttpResponse::html(INDEX_HTML),
+            ("GET", "/api/state") => return self.state_response(),
+            ("GET", "/api/diagram.svg") => return self.diagram_response(),
+            ("POST", "/api/new") => parse_body(&request.body).and_then(|f| new_game_command(&f)),
+            ("POST", "/api/match") => parse_body(&request.body).and_then(|f| new_match_command(&f)),
+            ("POST", "/api/rematch") => Ok(GameCommand::RematchCommand),
+            ("POST", "/api/put") => parse_body(&request.body).and_then(|f| put_piece_command(&f)),
+            ("POST", "/api/move") => parse_body(&request.body).and_then(|f| move_piece_command(&f)),
+            (
+                _,
+                "/" | "/index.html" | "/api/state" | "/api/diagram.svg" | "/api/events"
+                | "/api/new" | "/api/match" | "/api/rematch" | "/api/put" | "/api/move",
+            ) => {
+                return HttpResponse::json(
+                    405,
+                    json::error_to_json("MethodNotAllowed", "Méthode non autorisée"),
+                )
+            }
+            _ => {
+                return HttpResponse::json(
+                    404,
+                    json::error_to_json("NotFound", "Ressource introuvable"),
+                )
+            }
+        };
+
+        match command {
+            Ok(command) => self.execute(command),
+            Err(message) => bad_request(&message),
+        }
+    }
+
+    fn state_response(&self) -> HttpResponse {
+        match self.event_recorder.get_game_state() {
+            Some(game_state) => HttpResponse::json(200, json::game_state_to_json(&game_state)),
+            None => HttpResponse::json(
+                404,
+                json::error_to_json("CurrentlyNoGame", "Il n'y a aucune partie en cours"),
+            ),
+        }
+    }
+
+    fn diagram_response(&self) -> HttpResponse {
+        match self.event_recorder.get_game_state() {
+            Some(game_state) => HttpResponse {
+                status: 200,
+                content_type: "image/svg+xml",
+                body: game_state_to_svg(&game_state, &SvgOptions::default()),
+            },
+            None => HttpResponse::json(
+                404,
+                json::error_to_json("CurrentlyNoGame", "Il n'y a aucune partie en cours"),
+            ),
+        }
+    }
+
+    fn execute(&self, command: GameCommand) -> HttpResponse {
+        let (tx_events, rx_events) = mpsc::channel();
+
+        let events = self
+            .tx_request
+            .send(GameRequest { command, tx_events })
+            .ok()
+            .and_then(|_| rx_events.recv().ok());
+
+        match events.as_deref().map(find_game_error) {
+            Some(Some(game_error)) => HttpResponse::json(
+                game_error_status(game_error),
+                json::game_error_to_json(game_error),
+            ),
+            Some(None) => self.state_response(),
+            None => HttpResponse::json(
+                500,
+                json::error_to_json("UnknownError", "La partie ne répond plus"),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "ai")]
+    use crate::ai::random_strategy::RandomStrategy;
+    use crate::ihm::http::http_message::{HttpRequest, HttpResponse};
+    use crate::ihm::http::http_server::{find_game_error, HttpServer};
+    use crate::model::game_error::GameError::GameIsOver;
+    use crate::model::game_event::GameEvent::{
+        GameErrorEvent, GameTimeoutEvent, MoveAppliedEvent, NewGameEvent,
+    };
+    use crate::model::game_instance::GameInstance;
+    use crate::model::game_match::Match;
+    use crate::model::game_move::Move;
+    use crate::model::piece_size::PieceSize::Small;
+    #[cfg(feature = "ai")]
+    use crate::model::player::Color::Blue;
+    use crate::model::player::Color::Red;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+    use std::time::Duration;
+
+    fn request(http_server: &HttpServer, method: &str, path: &str, body: &str) -> HttpResponse {
+        http_server.handle(&HttpRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            body: body.to_string(),
+        })
+    }
+
+    #[test]
+    fn index_page_test() {
+        let http_server = HttpServer::new(Match::default());
+        let response = request(&http_server, "GET", "/", "");
+
+        assert_eq!(response.status, 200);
+        assert!(response.content_type.starts_with("text/html"));
+        assert!(response.body.contains("/api/events"));
+    }
+
+    #[test]
+    fn play_game_test() {
+        let http_server = HttpServer::new(Match::default());
+
+        assert_eq!(request(&http_server, "GET", "/api/state", "").status, 404);
+        assert_eq!(
+            request(&http_server, "GET", "/api/diagram.svg", "").status,
+            404
+        );
+        assert_eq!(
+            request(
+                &http_server,
+                "POST",
+                "/api/put",
+                "{\"x\":0,\"y\":0,\"size\":\"small\"}"
+            )
+            .status,
+            409
+        );
+
+        let response = request(&http_server, "POST", "/api/new", "");
+        assert_eq!(response.status, 200);
+        assert!(response.body.contains("\"turn\":0"));
+
+        let response = request(
+            &http_server,
+            "POST",
+            "/api/put",
+            "{\"x\":0,\"y\":0,\"size\":\"small\"}",
+        );
+        assert_eq!(response.status, 200);
+        assert!(response
+            .body
+            .contains("\"position\":\"S,-,-/-,-,-/-,-,- SMMBB ssmmbb b 1\""));
+
+        let response = request(
+            &http_server,
+            "POST",
+            "/api/move",
+            "{\"origin_x\":0,\"origin_y\":0,\"destination_x\":1,\"destination_y\":1}",
+        );
+        assert_eq!(response.status, 409);
+        assert!(response.body.contains("\"error\":\"NotYourPiece\""));
+
+        let response = request(&http_server, "GET", "/api/state", "");
+        assert_eq!(response.status, 200);
+        assert!(response.body.contains("\"turn\":1"));
+
+        let response = request(&http_server, "GET", "/api/diagram.svg", "");
+        assert_eq!(response.status, 200);
+        assert_eq!(response.content_type, "image/svg+xml");
+        assert!(response.body.contains("Tour 1 — au tour de Bleu"));
+    }
+
+    #[test]
+    fn new_game_options_test() {
+        let http_server = HttpServer::new(Match::default());
+
+        let response = request(
+            &http_server,
+            "POST",
+            "/api/new",
+            "{\"base\":60,\"increment\":2}",
+        );
+        assert_eq!(response.status, 200);
+        assert!(response.body.contains("\"remaining_time_ms\":60000"));
+
+        let response = request(
+            &http_server,
+            "POST",
+            "/api/new",
+            "{\"position\":\"-,-,-/-,B,-/-,-,- SSMMB ssmmbb b 1\"}",
+        );
+        assert_eq!(response.status, 200);
+        assert!(response.body.contains("\"current_color\":\"blue\""));
+
+        let response = request(
+            &http_server,
+            "POST",
+            "/api/new",
+            "{\"position\":\"-,-,-/-,B,-/-,-,- SSMMBB ssmmbb b 1\"}",
+        );
+        assert_eq!(response.status, 400);
+        assert!(response.body.contains("\"error\":\"InvalidPosition\""));
+    }
+
+    #[test]
+    fn match_test() {
+        let http_server = HttpServer::new(Match::default());
+
+        assert_eq!(
+            request(&http_server, "POST", "/api/rematch", "").status,
+            409
+        );
+        assert_eq!(
+            request(&http_server, "POST", "/api/match", "{}").status,
+            400
+        );
+
+        let response = request(&http_server, "POST", "/api/match", "{\"games\":3}");
+        assert_eq!(response.status, 200);
+        assert!(response.body.contains("\"current_color\":\"red\""));
+
+        for (x, y) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+            request(
+                &http_server,
+                "POST",
+                "/api/put",
+                &format!("{{\"x\":{},\"y\":{},\"size\":\"big\"}}", x, y),
+            );
+        }
+
+        let response = request(
+            &http_server,
+            "POST",
+            "/api/put",
+            "{\"x\":0,\"y\":2,\"size\":\"medium\"}",
+        );
+        assert_eq!(response.status, 200);
+        assert!(response
+            .body
+            .contains("\"turn\":0,\"current_color\":\"blue\""));
+
+        assert_eq!(
+            request(&http_server, "POST", "/api/rematch", "").status,
+            200
+        );
+    }
+
+    #[test]
+    fn bad_requests_test() {
+        let http_server = HttpServer::new(Match::default());
+        request(&http_server, "POST", "/api/new", "");
+
+        assert_eq!(request(&http_server, "GET", "/unknown", "").status, 404);
+        assert_eq!(request(&http_server, "GET", "/api/put", "").status, 405);
+        assert_eq!(request(&http_server, "POST", "/api/put", "{").status, 400);
+        assert_eq!(
+            request(&http_server, "POST", "/api/put", "{\"x\":0,\"y\":0}").status,
+            400
+        );
+        assert_eq!(
+            request(
+                &http_server,
+                "POST",
+                "/api/put",
+                "{\"x\":0,\"y\":0,\"size\":\"huge\"}"
+            )
+            .status,
+            400
+        );
+    }
+
+    #[test]
+    fn out_of_board_coordinates_test() {
+        let http_server = HttpServer::new(Match::default());
+        request(&http_server, "POST", "/api/new", "");
+
+        let response = request(
+            &http_server,
+            "POST",
+            "/api/put",
+            "{\"x\":5,\"y\":0,\"size\":\"big\"}",
+        );
+        assert_eq!(response.status, 400);
+        assert!(response.body.contains("compris entre 0 et 2"));
+        assert_eq!(
+            request(
+                &http_server,
+                "POST",
+                "/api/move",
+                "{\"origin_x\":0,\"origin_y\":0,\"destination_x\":1,\"destination_y\":3}",
+            )
+            .status,
+            400
+        );
+
+        let response = request(
+            &http_server,
+            "POST",
+            "/api/put",
+            "{\"x\":1,\"y\":1,\"size\":\"big\"}",
+        );
+        assert_eq!(response.status, 200);
+        assert!(response.body.contains("\"turn\":1"));
+    }
+
+    #[cfg(feature = "ai")]
+    #[test]
+    fn bot_answers_before_response_test() {
+        let mut game_match = Match::default();
+        game_match.set_bot(Blue, Box::new(RandomStrategy::new(3)));
+        let http_server = HttpServer::new(game_match);
+
+        request(&http_server, "POST", "/api/new", "");
+        let response = request(
+            &http_server,
+            "POST",
+            "/api/put",
+            "{\"x\":1,\"y\":1,\"size\":\"big\"}",
+        );
+
+        assert_eq!(response.status, 200);
+        assert!(response.body.contains("\"turn\":2"));
+    }
+
+    #[test]
+    fn tcp_connection_test() {
+        let http_server = HttpServer::new(Match::default());
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        thread::spawn(move || http_server.accept_connections(listener));
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream
+            .write_all(b"POST /api/new HTTP/1.1\r\nHost: localhost\r\nContent-Length: 0\r\n\r\n")
+            .unwrap();
+
+        let mut response = String::new();
+        BufReader::new(stream)
+            .read_to_string(&mut response)
+            .unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.ends_with("\"position\":\"-,-,-/-,-,-/-,-,- SSMMBB ssmmbb r 0\"}"));
+    }
+
+    #[test]
+    fn find_game_error_test() {
+        let game_instance = GameInstance::default();
+        let applied_move = game_instance
+            .clone()
+            .apply_move(Move::PutPiece(0, 0, Small))
+            .unwrap();
+
+        assert!(find_game_error(&[
+            NewGameEvent(game_instance.to_game_state()),
+            MoveAppliedEvent(applied_move)
+        ])
+        .is_none());
+        assert!(matches!(
+            find_game_error(&[
+                GameTimeoutEvent(Red),
+                GameErrorEvent(GameIsOver(String::from("La partie est terminée")))
+            ]),
+            Some(GameIsOver(_))
+        ));
+    }
+
+    #[test]
+    fn connection_limit_test() {
+        let http_server = HttpServer::new(Match::default()).with_max_connections(1);
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        thread::spawn(move || http_server.accept_connections(listener));
+
+        let _idle_connection = TcpStream::connect(address).unwrap();
+        let mut response = String::new();
+        TcpStream::connect(address)
+            .unwrap()
+            .read_to_string(&mut response)
+            .unwrap();
+        assert!(response.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+        assert!(response.contains("\"error\":\"ServiceUnavailable\""));
+    }
+
+    #[test]
+    fn spectator_limit_test() {
+        let http_server = HttpServer::new(Match::default())
+            .with_max_connections(1)
+            .with_max_spectators(1);
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        thread::spawn(move || http_server.accept_connections(listener));
+
+        let mut spectator = TcpStream::connect(address).unwrap();
+        spectator
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        spectator
+            .write_all(b"GET /api/events HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut status_line = String::new();
+        BufReader::new(&spectator)
+            .read_line(&mut status_line)
+            .unwrap();
+        assert_eq!(status_line, "HTTP/1.1 200 OK\r\n");
+
+        let mut response = String::new();
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream
+            .write_all(b"GET /api/events HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+        assert!(response.contains("spectateurs"));
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream
+            .write_all(b"POST /api/new HTTP/1.1\r\nHost: localhost\r\nContent-Length: 0\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    }
+
+    #[test]
+    fn spectator_stream_test() {
+        let http_server = HttpServer::new(Match::default());
+        request(&http_server, "POST", "/api/new", "");
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let spectator_server = http_server.clone();
+
+        thread::spawn(move || spectator_server.accept_connections(listener));
+
+        let mut spectators: Vec<_> = (0..2)
+            .map(|_| {
+                let mut stream = TcpStream::connect(address).unwrap();
+                stream
+                    .set_read_timeout(Some(Duration::from_secs(5)))
+                    .unwrap();
+                stream
+                    .write_all(b"GET /api/events HTTP/1.1\r\nHost: localhost\r\n\r\n")
+                    .unwrap();
+                BufReader::new(stream)
+            })
+            .collect();
+
+        let read_line = |spectator: &mut BufReader<TcpStream>| {
+            let mut line = String::new();
+            spectator.read_line(&mut line).unwrap();
+            line
+        };
+
+        for spectator in spectators.iter_mut() {
+            assert_eq!(read_line(spectator), "HTTP/1.1 200 OK\r\n");
+            assert_eq!(read_line(spectator), "Content-Type: text/event-stream\r\n");
+            while read_line(spectator) != "event: snapshot\n" {}
+            assert!(read_line(spectator).contains("\"turn\":0"));
+            assert_eq!(read_line(spectator), "\n");
+        }
+
+        request(
+            &http_server,
+            "POST",
+            "/api/put",
+            "{\"x\":0,\"y\":0,\"size\":\"small\"}",
+        );
+
+        for spectator in spectators.iter_mut() {
+            assert_eq!(read_line(spectator), "event: move_applied\n");
+            assert!(read_line(spectator).contains("\"turn\":1"));
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod event_recorder;
+pub mod http_message;
+pub mod http_server;
+pub mod spectator_broadcaster;
@@ -0,0 +1,75 @@
+use crate::ihm::json;
+use crate::model::game_event::GameEvent;
+use crate::model::listener::Listener;
+use std::sync::mpsc::{Receiver, Sender};
+use std::sync::{mpsc, Mutex};
+
+pub fn server_sent_event(name: &str, data: &str) -> String {
+    format!("event: {}\ndata: {}\n\n", name, data)
+}
+
+#[derive(Default)]
+pub struct SpectatorBroadcaster {
+    spectators: Mutex<Vec<Sender<String>>>,
+}
+
+impl SpectatorBroadcaster {
+    pub fn add_spectator(&self) -> Receiver<String> {
+        let (tx, rx) = mpsc::channel();
+        self.spectators.lock().unwrap().push(tx);
+        rx
+    }
+
+    pub fn spectator_count(&self) -> usize {
+        self.spectators.lock().unwrap().len()
+    }
+}
+
+impl Listener for SpectatorBroadcaster {
+    fn notify(&self, game_event: GameEvent) {
+        let message = server_sent_event(
+            json::game_event_name(&game_event),
+            &json::game_event_to_json(&game_event),
+        );
+
+        self.spectators
+            .lock()
+            .unwrap()
+            .retain(|spectator| spectator.send(message.clone()).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ihm::http::spectator_broadcaster::SpectatorBroadcaster;
+    use crate::model::game_event::GameEvent::{ExitEvent, NewGameEvent};
+    use crate::model::game_instance::GameInstance;
+    use crate::model::listener::Listener;
+
+    #[test]
+    fn broadcast_to_every_spectator_test() {
+        let spectator_broadcaster = SpectatorBroadcaster::default();
+        let first_spectator = spectator_broadcaster.add_spectator();
+        let second_spectator = spectator_broadcaster.add_spectator();
+
+        spectator_broadcaster.notify(NewGameEvent(GameInstance::default().to_game_state()));
+
+        for spectator in [&first_spectator, &second_spectator] {
+            let message = spectator.try_recv().unwrap();
+            assert!(message.starts_with("event: new_game\ndata: {\"board\":"));
+            assert!(message.ends_with("}\n\n"));
+        }
+    }
+
+    #[test]
+    fn forget_disconnected_spectators_test() {
+        let spectator_broadcaster = SpectatorBroadcaster::default();
+        let spectator = spectator_broadcaster.add_spectator();
+        drop(spectator_broadcaster.add_spectator());
+
+        spectator_broadcaster.notify(ExitEvent);
+
+        assert_eq!(spectator_broadcaster.spectator_count(), 1);
+        assert_eq!(spectator.try_recv().unwrap(), "event: exit\ndata: {}\n\n");
+    }
+}
@@ -0,0 +1,578 @@
+use crate::model::applied_move::AppliedMove;
+use crate::model::game_error::GameError;
+use crate::model::game_event::GameEvent;
+use crate::model::game_match::MatchScore;
+use crate::model::game_move::Move;
+use crate::model::game_state::{GameState, PieceState, PlayerState};
+use crate::model::piece_size::PieceSize;
+use crate::model::player::Color;
+
+#[derive(Debug, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+}
+
+pub fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            character if (character as u32) < 0x20 => {
+                escaped.push_str(&format!("\\u{:04x}", character as u32))
+            }
+            character => escaped.push(character),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+pub fn color_to_json(color: Color) -> String {
+    escape(match color {
+        Color::Red => "red",
+        Color::Blue => "blue",
+    })
+}
+
+pub fn piece_size_to_json(piece_size: PieceSize) -> String {
+    escape(piece_size_name(piece_size))
+}
+
+pub fn piece_size_name(piece_size: PieceSize) -> &'static str {
+    match piece_size {
+        PieceSize::Small => "small",
+        PieceSize::Medium => "medium",
+        PieceSize::Big => "big",
+    }
+}
+
+pub fn piece_size_from_name(name: &str) -> Option<PieceSize> {
+    match name {
+        "small" => Some(PieceSize::Small),
+        "medium" => Some(PieceSize::Medium),
+        "big" => Some(PieceSize::Big),
+        _ => None,
+    }
+}
+
+fn stack_to_json(square: &Option<PieceState>) -> String {
+    let mut pieces = Vec::new();
+    let mut piece_state = square.as_ref();
+
+    while let Some(current_piece_state) = piece_state {
+        pieces.push(format!(
+            "{{\"color\":{},\"size\":{}}}",
+            color_to_json(current_piece_state.color),
+            piece_size_to_json(current_piece_state.size)
+        ));
+        piece_state = current_piece_state.nested_piece.as_deref();
+    }
+
+    pieces.reverse();
+    format!("[{}]", pieces.join(","))
+}
+
+fn player_state_to_json(player_state: &PlayerState) -> String {
+    let pieces: Vec<String> = player_state
+        .pieces
+        .iter()
+        .map(|piece_size| piece_size_to_json(*piece_size))
+        .collect();
+
+    let remaining_time = match player_state.remaining_time {
+        Some(remaining_time) => remaining_time.as_millis().to_string(),
+        None => String::from("null"),
+    };
+
+    format!(
+        "{{\"color\":{},\"pieces\":[{}],\"remaining_time_ms\":{}}}",
+        color_to_json(player_state.color),
+        pieces.join(","),
+        remaining_time
+    )
+}
+
+pub fn game_state_to_json(game_state: &GameState) -> String {
+    let rows: Vec<String> = game_state
+        .board
+        .squares
+        .iter()
+        .map(|row| {
+            let squares: Vec<String> = row.iter().map(stack_to_json).collect();
+            format!("[{}]", squares.join(","))
+        })
+        .collect();
+
+    let players: Vec<String> = game_state
+        .players
+        .iter()
+        .map(player_state_to_json)
+        .collect();
+
+    let winner_color = match game_state.winner_color {
+        Some(winner_color) => color_to_json(winner_color),
+        None => String::from("null"),
+    };
+
+    format!(
+        "{{\"board\":[{}],\"players\":[{}],\"turn\":{},\"current_color\":{},\"winner_color\":{},\"draw\":{},\"position\":{}}}",
+        rows.join(","),
+        players.join(","),
+        game_state.turn,
+        color_to_json(game_state.current_color),
+        winner_color,
+        game_state.draw,
+        escape(&game_state.to_string())
+    )
+}
+
+fn square_to_json(square: Option<(usize, usize)>) -> String {
+    match square {
+        Some((x, y)) => format!("{{\"x\":{},\"y\":{}}}", x, y),
+        None => String::from("null"),
+    }
+}
+
+fn piece_to_json(piece: Option<(Color, PieceSize)>) -> String {
+    match piece {
+        Some((color, piece_size)) => format!(
+            "{{\"color\":{},\"size\":{}}}",
+            color_to_json(color),
+            piece_size_to_json(piece_size)
+        ),
+        None => String::from("null"),
+    }
+}
+
+pub fn applied_move_to_json(applied_move: &AppliedMove) -> String {
+    let remaining_time = match applied_move.remaining_time {
+        Some(remaining_time) => remaining_time.as_millis().to_string(),
+        None => String::from("null"),
+    };
+    let winner_color = match applied_move.winner_color {
+        Some(winner_color) => color_to_json(winner_color),
+        None => String::from("null"),
+    };
+
+    format!(
+        "{{\"color\":{},\"size\":{},\"origin\":{},\"destination\":{},\"covered_piece\":{},\"uncovered_piece\":{},\"turn\":{},\"remaining_time_ms\":{},\"winner_color\":{},\"draw\":{}}}",
+        color_to_json(applied_move.color),
+        piece_size_to_json(applied_move.size),
+        square_to_json(applied_move.origin),
+        square_to_json(Some(applied_move.destination)),
+        piece_to_json(applied_move.covered_piece),
+        piece_to_json(applied_move.uncovered_piece),
+        applied_move.turn,
+        remaining_time,
+        winner_color,
+        applied_move.draw
+    )
+}
+
+pub fn match_score_to_json(score: &MatchScore) -> String {
+    let winner_color = match score.winner() {
+        Some(winner_color) if score.is_over() => color_to_json(winner_color),
+        _ => String::from("null"),
+    };
+
+    format!(
+        "{{\"games_count\":{},\"games_played\":{},\"red_wins\":{},\"blue_wins\":{},\"draws\":{},\"over\":{},\"winner_color\":{}}}",
+        score.games_count,
+        score.games_played(),
+        score.red_wins,
+        score.blue_wins,
+        score.draws,
+        score.is_over(),
+        winner_color
+    )
+}
+
+pub fn error_to_json(name: &str, message: &str) -> String {
+    format!(
+        "{{\"error\":{},\"message\":{}}}",
+        escape(name),
+        escape(message)
+    )
+}
+
+pub fn game_error_to_json(game_error: &GameError) -> String {
+    let (name, message) = match game_error {
+        GameError::CurrentlyNoGame(message) => ("CurrentlyNoGame", message.as_str()),
+        GameError::CurrentlyNoMatch(message) => ("CurrentlyNoMatch", message.as_str()),
+        GameError::CannotPutPieceHere(message) => ("CannotPutPieceHere", message.as_str()),
+        GameError::SquareIsEmpty(message) => ("SquareIsEmpty", message.as_str()),
+        GameError::PieceNotAvailable(message) => ("PieceNotAvailable", message.as_str()),
+        GameError::NotYourPiece(message) => ("NotYourPiece", message.as_str()),
+        GameError::GameIsOver(message) => ("GameIsOver", message.as_str()),
+        GameError::InvalidPosition(message) => ("InvalidPosition", message.as_str()),
+        GameError::UnsupportedCommand(message) => ("UnsupportedCommand", message.as_str()),
+        GameError::UnknownError => ("UnknownError", "Erreur inconnue"),
+    };
+
+    error_to_json(name, message)
+}
+
+pub fn move_to_json(game_move: &Move) -> String {
+    match game_move {
+        Move::PutPiece(x, y, piece_size) => format!(
+            "{{\"type\":\"put\",\"x\":{},\"y\":{},\"size\":{}}}",
+            x,
+            y,
+            piece_size_to_json(*piece_size)
+        ),
+        Move::MovePiece(origin_x, origin_y, destination_x, destination_y) => format!(
+            "{{\"type\":\"move\",\"origin_x\":{},\"origin_y\":{},\"destination_x\":{},\"destination_y\":{}}}",
+            origin_x, origin_y, destination_x, destination_y
+        ),
+    }
+}
+
+pub fn game_event_name(game_event: &GameEvent) -> &'static str {
+    match game_event {
+        GameEvent::NewGameEvent(_) => "new_game",
+        GameEvent::BoardUpdateEvent(_) => "board_update",
+        GameEvent::MoveAppliedEvent(_) => "move_applied",
+        GameEvent::GameWinEvent(_) => "game_win",
+        GameEvent::GameTimeoutEvent(_) => "game_timeout",
+        GameEvent::GameForfeitEvent(_) => "game_forfeit",
+        GameEvent::GameErrorEvent(_) => "game_error",
+        GameEvent::HintEvent(_, _) => "hint",
+        GameEvent::AnalysisEvent(_) => "analysis",
+        GameEvent::MatchUpdateEvent(_) => "match_update",
+        GameEvent::MatchEndEvent(_) => "match_end",
+        GameEvent::ExitEvent => "exit",
+    }
+}
+
+pub fn game_event_to_json(game_event: &GameEvent) -> String {
+    match game_event {
+        GameEvent::NewGameEvent(game_state) | GameEvent::BoardUpdateEvent(game_state) => {
+            game_state_to_json(game_state)
+        }
+        GameEvent::MoveAppliedEvent(applied_move) => applied_move_to_json(applied_move),
+        GameEvent::GameWinEvent(color) => format!("{{\"winner_color\":{}}}", color_to_json(*color)),
+        GameEvent::GameTimeoutEvent(loser) | GameEvent::GameForfeitEvent(loser) => {
+            format!("{{\"loser_color\":{}}}", color_to_json(*loser))
+        }
+        GameEvent::GameErrorEvent(game_error) => game_error_to_json(game_error),
+        GameEvent::HintEvent(game_move, evaluation) => format!(
+            "{{\"move\":{},\"evaluation\":{}}}",
+            move_to_json(game_move),
+            escape(&evaluation.to_string())
+        ),
+        GameEvent::AnalysisEvent(report) => {
+            format!("{{\"report\":{}}}", escape(&report.to_string()))
+        }
+        GameEvent::MatchUpdateEvent(score) | GameEvent::MatchEndEvent(score) => {
+            match_score_to_json(score)
+        }
+        GameEvent::ExitEvent => String::from("{}"),
+    }
+}
+
+struct JsonParser<'a> {
+    characters: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl JsonParser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self
+            .characters
+            .peek()
+            .is_some_and(|character| character.is_whitespace())
+        {
+            self.characters.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_whitespace();
+        match self.characters.next() {
+            Some(character) if character == expected => Ok(()),
+            Some(character) => Err(format!(
+                "Caractère '{}' inattendu, '{}' attendu",
+                character, expected
+            )),
+            None => Err(format!("Fin du JSON inattendue, '{}' attendu", expected)),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut value = String::new();
+
+        loop {
+            match self.characters.next() {
+                Some('"') => return Ok(value),
+                Some('\\') => match self.characters.next() {
+                    Some('"') => value.push('"'),
+                    Some('\\') => value.push('\\'),
+                    Some('/') => value.push('/'),
+                    Some('n') => value.push('\n'),
+                    Some('r') => value.push('\r'),
+                    Some('t') => value.push('\t'),
+                    Some('u') => {
+                        let code: String = self.characters.by_ref().take(4).collect();
+                        let character = u32::from_str_radix(&code, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| format!("Séquence unicode invalide '{}'", code))?;
+                        value.push(character);
+                    }
+                    _ => return Err(String::from("Séquence d'échappement invalide")),
+                },
+                Some(character) => value.push(character),
+                None => return Err(String::from("Chaîne de caractères non terminée")),
+            }
+        }
+    }
+
+    fn parse_literal(&mut self) -> Result<JsonValue, String> {
+        let mut literal = String::new();
+
+        while let Some(character) = self
+            .characters
+            .peek()
+            .filter(|character| character.is_alphanumeric() || "+-.".contains(**character))
+        {
+            literal.push(*character);
+            self.characters.next();
+        }
+
+        match literal.as_str() {
+            "null" => Ok(JsonValue::Null),
+            "true" => Ok(JsonValue::Bool(true)),
+            "false" => Ok(JsonValue::Bool(false)),
+            _ => literal
+                .parse()
+                .map(JsonValue::Number)
+                .map_err(|_| format!("Valeur JSON invalide '{}'", literal)),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, String> {
+        self.skip_whitespace();
+        match self.characters.peek() {
+            Some('"') => self.parse_string().map(JsonValue::String),
+            Some(_) => self.parse_literal(),
+            None => Err(String::from("Valeur JSON manquante")),
+        }
+    }
+}
+
+pub fn parse_object(json: &str) -> Result<Vec<(String, JsonValue)>, String> {
+    let mut parser = JsonParser {
+        characters: json.chars().peekable(),
+    };
+    let mut fields = Vec::new();
+
+    parser.expect('{')?;
+    parser.skip_whitespace();
+
+    if parser.characters.peek() == Some(&'}') {
+        parser.characters.next();
+    } else {
+        loop {
+            parser.skip_whitespace();
+            let name = parser.parse_string()?;
+            parser.expect(':')?;
+            fields.push((name, parser.parse_value()?));
+
+            parser.skip_whitespace();
+            match parser.characters.next() {
+                Some(',') => continue,
+                Some('}') => break,
+                _ => return Err(String::from("',' ou '}' attendu dans l'objet JSON")),
+            }
+        }
+    }
+
+    parser.skip_whitespace();
+    match parser.characters.next() {
+        None => Ok(fields),
+        Some(character) => Err(format!(
+            "Caractère '{}' inattendu après l'objet JSON",
+            character
+        )),
+    }
+}
+
+pub fn get_field<'a>(fields: &'a [(String, JsonValue)], name: &str) -> Option<&'a JsonValue> {
+    fields
+        .iter()
+        .find(|(field_name, _)| field_name == name)
+        .map(|(_, value)| value)
+}
+
+pub fn get_usize(fields: &[(String, JsonValue)], name: &str) -> Result<usize, String> {
+    match get_field(fields, name) {
+        Some(JsonValue::Number(number)) if *number >= 0.0 && number.fract() == 0.0 => {
+            Ok(*number as usize)
+        }
+        Some(_) => Err(format!("Le champ \"{}\" doit être un entier positif", name)),
+        None => Err(format!("Le champ \"{}\" est manquant", name)),
+    }
+}
+
+pub fn get_string<'a>(fields: &'a [(String, JsonValue)], name: &str) -> Result<&'a str, String> {
+    match get_field(fields, name) {
+        Some(JsonValue::String(value)) => Ok(value),
+        Some(_) => Err(format!(
+            "Le champ \"{}\" doit être une chaîne de caractères",
+            name
+        )),
+        None => Err(format!("Le champ \"{}\" est manquant", name)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ihm::json::JsonValue::{Bool, Null, Number, String as JsonString};
+    use crate::ihm::json::{
+        escape, game_error_to_json, game_event_name, game_event_to_json, game_state_to_json,
+        get_string, get_usize, match_score_to_json, parse_object,
+    };
+    use crate::model::evaluation::Evaluation;
+    use crate::model::game_error::GameError::NotYourPiece;
+    use crate::model::game_event::GameEvent::{
+        ExitEvent, GameTimeoutEvent, HintEvent, MoveAppliedEvent,
+    };
+    use crate::model::game_instance::GameInstance;
+    use crate::model::game_match::MatchScore;
+    use crate::model::game_move::Move;
+    use crate::model::piece_size::PieceSize::{Big, Small};
+    use crate::model::player::Color::{Blue, Red};
+
+    #[test]
+    fn escape_test() {
+        assert_eq!(escape("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+        assert_eq!(escape("pièce"), "\"pièce\"");
+    }
+
+    #[test]
+    fn game_state_to_json_test() {
+        let mut game_instance = GameInstance::default();
+        game_instance.put_piece(1, 1, Small).unwrap();
+        game_instance.put_piece(1, 1, Big).unwrap();
+
+        let json = game_state_to_json(&game_instance.to_game_state());
+
+        assert!(json.starts_with(
+            "{\"board\":[[[],[],[]],[[],[{\"color\":\"red\",\"size\":\"small\"},{\"color\":\"blue\",\"size\":\"big\"}],[]],[[],[],[]]]"
+        ));
+        assert!(json.contains(
+            "{\"color\":\"blue\",\"pieces\":[\"small\",\"small\",\"medium\",\"medium\",\"big\"],\"remaining_time_ms\":null}"
+        ));
+        assert!(json.ends_with(
+            "\"turn\":2,\"current_color\":\"red\",\"winner_color\":null,\"draw\":false,\"position\":\"-,-,-/-,Sb,-/-,-,- SMMBB ssmmb r 2\"}"
+        ));
+    }
+
+    #[test]
+    fn game_error_to_json_test() {
+        assert_eq!(
+            game_error_to_json(&NotYourPiece(String::from("Pas à toi"))),
+            "{\"error\":\"NotYourPiece\",\"message\":\"Pas à toi\"}"
+        );
+    }
+
+    #[test]
+    fn game_event_to_json_test() {
+        let hint_event = HintEvent(Move::PutPiece(1, 2, Big), Evaluation::Win(3));
+
+        assert_eq!(game_event_name(&hint_event), "hint");
+        assert_eq!(
+            game_event_to_json(&hint_event),
+            format!(
+                "{{\"move\":{{\"type\":\"put\",\"x\":1,\"y\":2,\"size\":\"big\"}},\"evaluation\":{}}}",
+                escape(&Evaluation::Win(3).to_string())
+            )
+        );
+        assert_eq!(
+            game_event_to_json(&GameTimeoutEvent(Red)),
+            "{\"loser_color\":\"red\"}"
+        );
+        assert_eq!(game_event_name(&ExitEvent), "exit");
+        assert!(
+            game_event_to_json(&HintEvent(Move::MovePiece(0, 1, 2, 0), Evaluation::Score(0)))
+                .starts_with("{\"move\":{\"type\":\"move\",\"origin_x\":0,\"origin_y\":1,\"destination_x\":2,\"destination_y\":0},")
+        );
+
+        let mut game_instance = GameInstance::default();
+        game_instance.put_piece(1, 1, Small).unwrap();
+        game_instance.put_piece(0, 0, Big).unwrap();
+        game_instance.put_piece(2, 2, Small).unwrap();
+        let move_applied_event = MoveAppliedEvent(
+            game_instance
+                .apply_move(Move::MovePiece(0, 0, 1, 1))
+                .unwrap(),
+        );
+
+        assert_eq!(game_event_name(&move_applied_event), "move_applied");
+        assert_eq!(
+            game_event_to_json(&move_applied_event),
+            "{\"color\":\"blue\",\"size\":\"big\",\"origin\":{\"x\":0,\"y\":0},\"destination\":{\"x\":1,\"y\":1},\"covered_piece\":{\"color\":\"red\",\"size\":\"small\"},\"uncovered_piece\":null,\"turn\":4,\"remaining_time_ms\":null,\"winner_color\":null,\"draw\":false}"
+        );
+    }
+
+    #[test]
+    fn match_score_to_json_test() {
+        let mut score = MatchScore::new(3);
+        score.record(Some(Blue));
+        score.record(Some(Blue));
+
+        assert_eq!(
+            match_score_to_json(&score),
+            "{\"games_count\":3,\"games_played\":2,\"red_wins\":0,\"blue_wins\":2,\"draws\":0,\"over\":true,\"winner_color\":\"blue\"}"
+        );
+    }
+
+    #[test]
+    fn parse_object_test() {
+        let fields = parse_object(
+            " { \"x\" : 1, \"size\": \"b\\\"ig\", \"a\": null, \"b\": true, \"c\": -2.5 } ",
+        )
+        .unwrap();
+
+        assert_eq!(
+            fields,
+            vec![
+                (String::from("x"), Number(1.0)),
+                (String::from("size"), JsonString(String::from("b\"ig"))),
+                (String::from("a"), Null),
+                (String::from("b"), Bool(true)),
+                (String::from("c"), Number(-2.5)),
+            ]
+        );
+        assert_eq!(get_usize(&fields, "x"), Ok(1));
+        assert_eq!(get_string(&fields, "size"), Ok("b\"ig"));
+        assert!(get_usize(&fields, "c").is_err());
+        assert!(get_usize(&fields, "y").is_err());
+        assert!(parse_object("{}").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_invalid_object_test() {
+        for json in [
+            "",
+            "[]",
+            "{\"x\":}",
+            "{\"x\":1",
+            "{\"x\" 1}",
+            "{\"x\":1} 2",
+            "{\"x\":\"a}",
+        ] {
+            assert!(parse_object(json).is_err(), "{}", json);
+        }
+    }
+}
@@ -1,2 +1,11 @@
+#[cfg(not(target_arch = "wasm32"))]
 pub mod channel_listener;
+#[cfg(all(feature = "console", not(target_arch = "wasm32")))]
 pub mod debug_console;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod game_thread;
+#[cfg(all(feature = "networking", not(target_arch = "wasm32")))]
+pub mod http;
+#[cfg(feature = "serialization")]
+pub mod json;
+pub mod svg_diagram;
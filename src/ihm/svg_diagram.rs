@@ -0,0 +1,331 @@
+use crate::model::game_error::GameError;
+use crate::model::game_instance::GameInstance;
+use crate::model::game_move::Move;
+use crate::model::game_state::{GameState, PieceState};
+use crate::model::piece_size::PieceSize;
+use crate::model::player::Color;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const SQUARE_SIZE: u32 = 100;
+const MARGIN: u32 = 20;
+const RESERVE_HEIGHT: u32 = 90;
+const CAPTION_HEIGHT: u32 = 30;
+const BOARD_SIZE: u32 = 3 * SQUARE_SIZE;
+const RESERVE_SPACING: u32 = 50;
+
+pub struct SvgOptions {
+    pub show_nested_pieces: bool,
+    pub show_reserves: bool,
+    pub caption: Option<String>,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        SvgOptions {
+            show_nested_pieces: true,
+            show_reserves: true,
+            caption: None,
+        }
+    }
+}
+
+fn piece_radius(piece_size: PieceSize) -> u32 {
+    match piece_size {
+        PieceSize::Small => 16,
+        PieceSize::Medium => 29,
+        PieceSize::Big => 42,
+    }
+}
+
+fn piece_color(color: Color) -> &'static str {
+    match color {
+        Color::Red => "#d33",
+        Color::Blue => "#36c",
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn write_piece(svg: &mut String, center_x: u32, center_y: u32, color: Color, radius: u32) {
+    let _ = writeln!(
+        svg,
+        r##"<circle cx="{}" cy="{}" r="{}" fill="{}" stroke="#222" stroke-width="2"/>"##,
+        center_x,
+        center_y,
+        radius,
+        piece_color(color)
+    );
+}
+
+fn write_nested_piece(svg: &mut String, center_x: u32, center_y: u32, piece_state: &PieceState) {
+    let _ = writeln!(
+        svg,
+        r##"<circle cx="{}" cy="{}" r="{}" fill="{}" fill-opacity="0.35" stroke="#fff" stroke-width="2" stroke-dasharray="4 3"/>"##,
+        center_x,
+        center_y,
+        piece_radius(piece_state.size),
+        piece_color(piece_state.color)
+    );
+}
+
+fn write_reserve(svg: &mut String, game_state: &GameState, color: Color, top: u32) {
+    let pieces = game_state
+        .players
+        .iter()
+        .find(|player_state| player_state.color == color)
+        .map(|player_state| player_state.pieces.clone())
+        .unwrap_or_default();
+
+    let _ = writeln!(
+        svg,
+        r##"<text x="{}" y="{}" font-family="sans-serif" font-size="14" fill="#333">{}</text>"##,
+        MARGIN,
+        top + 14,
+        color
+    );
+
+    for (index, piece_size) in pieces.into_iter().enumerate() {
+        let center_x = MARGIN + RESERVE_SPACING / 2 + index as u32 * RESERVE_SPACING;
+        write_piece(
+            svg,
+            center_x,
+            top + RESERVE_HEIGHT / 2 + 8,
+            color,
+            piece_radius(piece_size) * RESERVE_SPACING / (2 * piece_radius(PieceSize::Big) + 4),
+        );
+    }
+}
+
+pub fn game_state_to_svg(game_state: &GameState, options: &SvgOptions) -> String {
+    let reserve_height = if options.show_reserves {
+        RESERVE_HEIGHT
+    } else {
+        0
+    };
+    let board_top = MARGIN + reserve_height;
+    let width = BOARD_SIZE + 2 * MARGIN;
+    let height = board_top + BOARD_SIZE + reserve_height + CAPTION_HEIGHT + MARGIN;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+        width, height
+    );
+    let _ = writeln!(
+        svg,
+        r##"<rect width="{}" height="{}" fill="#fff"/>"##,
+        width, height
+    );
+
+    if options.show_reserves {
+        write_reserve(&mut svg, game_state, Color::Blue, MARGIN);
+    }
+
+    for (x, row) in game_state.board.squares.iter().enumerate() {
+        for (y, square) in row.iter().enumerate() {
+            let left = MARGIN + y as u32 * SQUARE_SIZE;
+            let top = board_top + x as u32 * SQUARE_SIZE;
+            let _ = writeln!(
+                svg,
+                r##"<rect x="{}" y="{}" width="{}" height="{}" fill="#eee" stroke="#999" stroke-width="2"/>"##,
+                left, top, SQUARE_SIZE, SQUARE_SIZE
+            );
+
+            let piece_state = match square {
+                Some(piece_state) => piece_state,
+                None => continue,
+            };
+
+            let center_x = left + SQUARE_SIZE / 2;
+            let center_y = top + SQUARE_SIZE / 2;
+            write_piece(
+                &mut svg,
+                center_x,
+                center_y,
+                piece_state.color,
+                piece_radius(piece_state.size),
+            );
+
+            if options.show_nested_pieces {
+                let mut nested_piece = piece_state.nested_piece.as_deref();
+                while let Some(nested_piece_state) = nested_piece {
+                    write_nested_piece(&mut svg, center_x, center_y, nested_piece_state);
+                    nested_piece = nested_piece_state.nested_piece.as_deref();
+                }
+            }
+        }
+    }
+
+    if options.show_reserves {
+        write_reserve(
+            &mut svg,
+            game_state,
+            Color::Red,
+            board_top + BOARD_SIZE + MARGIN / 2,
+        );
+    }
+
+    let caption = match &options.caption {
+        Some(caption) => caption.clone(),
+        None => match game_state.winner_color {
+            Some(winner_color) => format!("Tour {} — victoire : {}", game_state.turn, winner_color),
+            None if game_state.draw => format!("Tour {} — partie nulle", game_state.turn),
+            None => format!(
+                "Tour {} — au tour de {}",
+                game_state.turn, game_state.current_color
+            ),
+        },
+    };
+    let _ = writeln!(
+        svg,
+        r##"<text x="{}" y="{}" font-family="sans-serif" font-size="16" text-anchor="middle" fill="#222">{}</text>"##,
+        width / 2,
+        height - MARGIN,
+        escape_xml(&caption)
+    );
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+pub fn game_diagrams(
+    initial_game_instance: &GameInstance,
+    history: &[Move],
+    options: &SvgOptions,
+) -> Result<Vec<String>, GameError> {
+    let mut game_instance = initial_game_instance.without_clock();
+    let mut diagrams = vec![game_state_to_svg(
+        &game_instance.to_game_state(),
+        &SvgOptions {
+            caption: Some(String::from("Position initiale")),
+            ..*options
+        },
+    )];
+
+    for (index, game_move) in history.iter().enumerate() {
+        let color = game_instance.get_current_color();
+        game_instance.play(*game_move)?;
+        diagrams.push(game_state_to_svg(
+            &game_instance.to_game_state(),
+            &SvgOptions {
+                caption: Some(format!("{}. {} {}", index + 1, color, game_move)),
+                ..*options
+            },
+        ));
+    }
+
+    Ok(diagrams)
+}
+
+pub fn export_diagrams(diagrams: &[String], directory: &Path) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(directory)?;
+
+    diagrams
+        .iter()
+        .enumerate()
+        .map(|(index, diagram)| {
+            let path = directory.join(format!("diagramme-{:03}.svg", index));
+            fs::write(&path, diagram).map(|_| path)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ihm::svg_diagram::{export_diagrams, game_diagrams, game_state_to_svg, SvgOptions};
+    use crate::model::game_instance::GameInstance;
+    use crate::model::game_move::Move;
+    use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+    use std::fs;
+
+    fn covered_game_instance() -> GameInstance {
+        let mut game_instance = GameInstance::default();
+        game_instance.put_piece(1, 1, Small).unwrap();
+        game_instance.put_piece(1, 1, Medium).unwrap();
+        game_instance
+    }
+
+    #[test]
+    fn start_position_svg_test() {
+        let svg = game_state_to_svg(
+            &GameInstance::default().to_game_state(),
+            &SvgOptions::default(),
+        );
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<rect x=").count(), 9);
+        assert_eq!(svg.matches("<circle").count(), 12);
+        assert!(svg.contains("Tour 0 — au tour de Rouge"));
+    }
+
+    #[test]
+    fn nested_pieces_svg_test() {
+        let game_state = covered_game_instance().to_game_state();
+
+        let svg = game_state_to_svg(&game_state, &SvgOptions::default());
+        assert_eq!(svg.matches("stroke-dasharray").count(), 1);
+        assert!(svg.contains(r##"cx="170" cy="260" r="29" fill="#36c""##));
+        assert!(svg.contains(r##"cx="170" cy="260" r="16" fill="#d33" fill-opacity="0.35""##));
+
+        let svg = game_state_to_svg(
+            &game_state,
+            &SvgOptions {
+                show_nested_pieces: false,
+                show_reserves: false,
+                caption: Some(String::from("<Diagramme & co>")),
+            },
+        );
+        assert_eq!(svg.matches("<circle").count(), 1);
+        assert!(svg.contains("&lt;Diagramme &amp; co&gt;"));
+    }
+
+    #[test]
+    fn game_diagrams_test() {
+        let history = vec![
+            Move::PutPiece(0, 0, Big),
+            Move::PutPiece(2, 2, Small),
+            Move::MovePiece(0, 0, 1, 1),
+        ];
+
+        let diagrams =
+            game_diagrams(&GameInstance::default(), &history, &SvgOptions::default()).unwrap();
+
+        assert_eq!(diagrams.len(), 4);
+        assert!(diagrams[0].contains("Position initiale"));
+        assert!(diagrams[1].contains("1. Rouge pose une grande en (0, 0)"));
+        assert!(diagrams[3].contains("3. Rouge déplace (0, 0) vers (1, 1)"));
+
+        assert!(game_diagrams(
+            &GameInstance::default(),
+            &[Move::PutPiece(0, 0, Big), Move::PutPiece(0, 0, Medium)],
+            &SvgOptions::default()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn export_diagrams_test() {
+        let directory = std::env::temp_dir().join(format!(
+            "gobblets_gobblers_svg_export_test_{}",
+            std::process::id()
+        ));
+        let diagrams = vec![String::from("<svg/>"), String::from("<svg></svg>")];
+
+        let paths = export_diagrams(&diagrams, &directory).unwrap();
+
+        assert_eq!(paths.len(), 2);
+        assert!(paths[1].ends_with("diagramme-001.svg"));
+        assert_eq!(fs::read_to_string(&paths[1]).unwrap(), "<svg></svg>");
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
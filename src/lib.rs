@@ -0,0 +1,20 @@
+#[cfg(feature = "ai")]
+pub mod ai;
+#[cfg(feature = "ai")]
+pub mod engine;
+#[cfg(all(feature = "ffi", not(target_arch = "wasm32")))]
+pub mod ffi;
+pub mod ihm;
+pub mod model;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod rating;
+#[cfg(feature = "serialization")]
+pub mod wasm;
+
+pub use crate::model::game::Game;
+pub use crate::model::game_command::GameCommand;
+pub use crate::model::game_event::GameEvent;
+pub use crate::model::game_instance::GameInstance;
+pub use crate::model::game_state::GameState;
+pub use crate::model::listener::Listener;
@@ -1,32 +1,288 @@
-use crate::ihm::debug_console::debug_console::DebugConsole;
-use crate::model::game::Game;
+use gobblets_gobblers::ai::strategy::{strategy_from_name, Strategy};
+use gobblets_gobblers::ihm::debug_console::debug_console::DebugConsole;
+use gobblets_gobblers::ihm::debug_console::script_runner::ScriptRunner;
+use gobblets_gobblers::ihm::game_thread::spawn_game_thread;
+use gobblets_gobblers::ihm::http::http_server::HttpServer;
+use gobblets_gobblers::ihm::svg_diagram::{game_state_to_svg, SvgOptions};
+use gobblets_gobblers::model::game_instance::GameInstance;
+use gobblets_gobblers::model::game_match::Match;
+use gobblets_gobblers::model::game_state::GameState;
+use gobblets_gobblers::model::listener::Listener;
+use gobblets_gobblers::model::player::Color;
+use gobblets_gobblers::rating::rating_listener::RatingListener;
+use gobblets_gobblers::rating::rating_store::RatingStore;
+use std::fs::File;
+use std::io;
+use std::io::BufReader;
+use std::path::Path;
 use std::sync::mpsc;
-use std::thread;
+use std::sync::Arc;
+use std::{env, process};
 
-pub mod model;
+const DEFAULT_HTTP_ADDRESS: &str = "127.0.0.1:8080";
+const DEFAULT_RATINGS_PATH: &str = "ratings.txt";
+const HUMAN_PLAYER_NAME: &str = "humain";
 
-pub mod ihm;
-fn main() {
-    let (tx_command, rx_command) = mpsc::channel();
+fn get_option_value<'a>(args: &'a [String], option: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|arg| arg == option)
+        .and_then(|index| args.get(index + 1))
+        .map(|value| value.as_str())
+}
+
+fn get_option_values<'a>(args: &'a [String], option: &str) -> Vec<&'a str> {
+    args.windows(2)
+        .filter(|pair| pair[0] == option)
+        .map(|pair| pair[1].as_str())
+        .collect()
+}
+
+fn parse_bot(value: &str) -> Result<(Color, Box<dyn Strategy>), String> {
+    let (color, strategy_name) = value
+        .split_once(':')
+        .ok_or_else(|| format!("Bot invalide : {}", value))?;
+    let color = color
+        .parse()
+        .map_err(|_| format!("Couleur inconnue : {}", color))?;
+    let strategy = strategy_from_name(strategy_name)?;
+
+    Ok((color, strategy))
+}
+
+fn parse_player(value: &str) -> Result<(Color, String), String> {
+    let (color, name) = value
+        .split_once(':')
+        .ok_or_else(|| format!("Joueur invalide : {}", value))?;
+    let color = color
+        .parse()
+        .map_err(|_| format!("Couleur inconnue : {}", color))?;
+
+    if name.is_empty() || name.contains(['\t', '\n', '\r']) {
+        return Err(format!("Nom de joueur invalide : {}", name));
+    }
+
+    Ok((color, name.to_string()))
+}
+
+fn load_rating_store(path: &Path) -> RatingStore {
+    if !path.exists() {
+        return RatingStore::default();
+    }
+
+    match RatingStore::load(path) {
+        Ok(rating_store) => rating_store,
+        Err(error) => {
+            eprintln!("Impossible de charger le classement : {}", error);
+            process::exit(1);
+        }
+    }
+}
+
+fn create_rating_listener(args: &[String], path: &Path) -> Result<RatingListener, String> {
+    let mut player_names = [
+        String::from(HUMAN_PLAYER_NAME),
+        String::from(HUMAN_PLAYER_NAME),
+    ];
+
+    for value in get_option_values(args, "--bot")
+        .into_iter()
+        .chain(get_option_values(args, "--player"))
+    {
+        let (color, name) = parse_player(value)?;
+        player_names[(color == Color::Blue) as usize] = name;
+    }
+
+    let [red_player, blue_player] = player_names;
 
-    let mut game = Game::default();
+    Ok(RatingListener::new(
+        path.to_path_buf(),
+        load_rating_store(path),
+        &red_player,
+        &blue_player,
+    ))
+}
+
+fn create_match(
+    bots: Vec<(Color, Box<dyn Strategy>)>,
+    rating_listener: Option<RatingListener>,
+) -> Match {
+    let mut game_match = Match::default();
 
-    let mut debug_console = DebugConsole::new(tx_command.clone());
+    for (color, strategy) in bots {
+        game_match.set_bot(color, strategy);
+    }
 
-    game.subscribe(debug_console.get_channel_listener());
+    if let Some(rating_listener) = rating_listener {
+        game_match.subscribe(Arc::new(rating_listener) as Arc<dyn Listener>);
+    }
+
+    game_match
+}
 
-    let game_handle = thread::spawn(move || loop {
-        let exit = match rx_command.recv() {
-            Ok(game_command) => game.execute(game_command),
-            Err(_) => false,
-        };
+fn run_perft(depth: u32, divide: bool) {
+    let game_instance = GameInstance::default();
 
-        if exit {
-            break;
+    if divide {
+        let mut total = 0;
+        for (game_move, count) in game_instance.perft_divide(depth) {
+            println!("{} : {}", game_move, count);
+            total += count;
         }
-    });
+        println!("Total : {}", total);
+    } else {
+        println!("perft({}) = {}", depth, game_instance.perft(depth));
+    }
+}
+
+fn run_svg_export(position: &str, options: &SvgOptions) {
+    match position.parse::<GameState>() {
+        Ok(game_state) => print!("{}", game_state_to_svg(&game_state, options)),
+        Err(error) => {
+            eprintln!("Position invalide : {:?}", error);
+            process::exit(2);
+        }
+    }
+}
+
+fn run_leaderboard(path: &Path) {
+    println!("{}", load_rating_store(path));
+}
+
+fn run_debug_console(mut game_match: Match) {
+    let (tx_command, rx_command) = mpsc::channel();
+
+    let mut debug_console = DebugConsole::new(tx_command);
+
+    game_match.subscribe(debug_console.get_channel_listener());
+
+    let game_handle = spawn_game_thread(game_match, rx_command);
 
     debug_console.start();
 
     game_handle.join().unwrap();
+    debug_console.join();
+}
+
+fn run_script(path: &Path, stop_on_error: bool, game_match: Match) {
+    let script = match File::open(path) {
+        Ok(file) => BufReader::new(file),
+        Err(error) => {
+            eprintln!(
+                "Impossible d'ouvrir le script {} : {}",
+                path.display(),
+                error
+            );
+            process::exit(1);
+        }
+    };
+
+    match ScriptRunner::new(stop_on_error).run(script, game_match, &mut io::stdout()) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(error) => {
+            eprintln!("Erreur lors de l'exécution du script : {}", error);
+            process::exit(1);
+        }
+    }
+}
+
+fn run_http_server(address: &str, game_match: Match) {
+    if let Err(error) = HttpServer::new(game_match).serve(address) {
+        eprintln!("Impossible de démarrer le serveur HTTP : {}", error);
+        process::exit(1);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.iter().any(|arg| arg == "--perft") {
+        match get_option_value(&args, "--perft").map(str::parse::<u32>) {
+            Some(Ok(depth)) => run_perft(depth, args.iter().any(|arg| arg == "--divide")),
+            _ => {
+                eprintln!("Usage : --perft <profondeur> [--divide]");
+                process::exit(2);
+            }
+        }
+        return;
+    }
+
+    if args.iter().any(|arg| arg == "--svg") {
+        match get_option_value(&args, "--svg") {
+            Some(position) => run_svg_export(
+                position,
+                &SvgOptions {
+                    show_nested_pieces: !args.iter().any(|arg| arg == "--hide-nested"),
+                    show_reserves: !args.iter().any(|arg| arg == "--hide-reserves"),
+                    caption: None,
+                },
+            ),
+            None => {
+                eprintln!("Usage : --svg <position> [--hide-nested] [--hide-reserves]");
+                process::exit(2);
+            }
+        }
+        return;
+    }
+
+    let ratings_path =
+        Path::new(get_option_value(&args, "--ratings").unwrap_or(DEFAULT_RATINGS_PATH));
+
+    if args.iter().any(|arg| arg == "--leaderboard") {
+        run_leaderboard(ratings_path);
+        return;
+    }
+
+    let bots = match get_option_values(&args, "--bot")
+        .into_iter()
+        .map(parse_bot)
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(bots) => bots,
+        Err(error) => {
+            eprintln!("{}", error);
+            eprintln!("Usage : --bot <rouge|bleu>:<minimax|mcts|random|learned:<fichier>|engine:<commande>>");
+            process::exit(2);
+        }
+    };
+
+    let rating_listener = if args.iter().any(|arg| arg == "--ratings") {
+        match create_rating_listener(&args, ratings_path) {
+            Ok(rating_listener) => Some(rating_listener),
+            Err(error) => {
+                eprintln!("{}", error);
+                eprintln!("Usage : --ratings <fichier> [--player <rouge|bleu>:<nom>]");
+                process::exit(2);
+            }
+        }
+    } else {
+        None
+    };
+
+    let game_match = create_match(bots, rating_listener);
+
+    if args.iter().any(|arg| arg == "--http") {
+        let address = get_option_value(&args, "--http")
+            .filter(|value| !value.starts_with("--"))
+            .unwrap_or(DEFAULT_HTTP_ADDRESS);
+        run_http_server(address, game_match);
+        return;
+    }
+
+    if args.iter().any(|arg| arg == "--script") {
+        match get_option_value(&args, "--script") {
+            Some(path) => run_script(
+                Path::new(path),
+                args.iter().any(|arg| arg == "--stop-on-error"),
+                game_match,
+            ),
+            None => {
+                eprintln!("Usage : --script <fichier> [--stop-on-error]");
+                process::exit(2);
+            }
+        }
+        return;
+    }
+
+    run_debug_console(game_match);
 }
@@ -0,0 +1,130 @@
+use crate::model::game_move::Move;
+use crate::model::player::Color;
+use crate::model::solution::Solution;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::{fmt, fs, io};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct AnalyzedMove {
+    pub turn: u32,
+    pub color: Color,
+    pub played_move: Move,
+    pub played_solution: Option<Solution>,
+    pub best_solution: Option<Solution>,
+}
+
+impl AnalyzedMove {
+    pub fn is_blunder(&self) -> bool {
+        match (self.played_solution, self.best_solution) {
+            (Some(played_solution), Some(best_solution)) => {
+                played_solution.outcome < best_solution.outcome
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct AnalysisReport {
+    pub max_positions: usize,
+    pub moves: Vec<AnalyzedMove>,
+    pub winner: Option<Color>,
+}
+
+impl AnalysisReport {
+    pub fn blunders(&self) -> impl Iterator<Item = &AnalyzedMove> {
+        self.moves
+            .iter()
+            .filter(|analyzed_move| analyzed_move.is_blunder())
+    }
+
+    pub fn export(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+}
+
+impl Display for AnalysisReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Analyse de la partie ({} positions au plus par coup)",
+            self.max_positions
+        )?;
+
+        for analyzed_move in &self.moves {
+            write!(
+                f,
+                "{}. {} {} : ",
+                analyzed_move.turn, analyzed_move.color, analyzed_move.played_move
+            )?;
+
+            match analyzed_move.played_solution {
+                Some(played_solution) => write!(f, "{}", played_solution)?,
+                None => write!(f, "issue inconnue")?,
+            }
+
+            if analyzed_move.is_blunder() {
+                write!(f, " ?? gaffe")?;
+                if let Some(best_solution) = analyzed_move.best_solution {
+                    if let Some(best_move) = best_solution.best_move {
+                        write!(f, ", meilleur coup : {} ({})", best_move, best_solution)?;
+                    }
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        match self.winner {
+            Some(winner) => writeln!(f, "Vainqueur : {}", winner)?,
+            None => writeln!(f, "Aucun vainqueur")?,
+        }
+
+        writeln!(f, "{} gaffe(s) relevée(s)", self.blunders().count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::analysis_report::AnalyzedMove;
+    use crate::model::game_move::Move;
+    use crate::model::piece_size::PieceSize::Big;
+    use crate::model::player::Color::Red;
+    use crate::model::solution::{Outcome, Solution};
+
+    fn solution(outcome: Outcome) -> Option<Solution> {
+        Some(Solution {
+            outcome,
+            plies: 1,
+            best_move: None,
+        })
+    }
+
+    #[test]
+    fn blunder_is_a_drop_in_proven_outcome_test() {
+        let analyzed_move = |played_solution, best_solution| AnalyzedMove {
+            turn: 1,
+            color: Red,
+            played_move: Move::PutPiece(0, 0, Big),
+            played_solution,
+            best_solution,
+        };
+
+        assert!(analyzed_move(solution(Outcome::Draw), solution(Outcome::Win)).is_blunder());
+        assert!(analyzed_move(solution(Outcome::Loss), solution(Outcome::Win)).is_blunder());
+        assert!(analyzed_move(solution(Outcome::Loss), solution(Outcome::Draw)).is_blunder());
+        assert!(!analyzed_move(solution(Outcome::Draw), solution(Outcome::Draw)).is_blunder());
+        assert!(!analyzed_move(solution(Outcome::Win), solution(Outcome::Win)).is_blunder());
+        assert!(!analyzed_move(None, solution(Outcome::Win)).is_blunder());
+        assert!(!analyzed_move(solution(Outcome::Loss), None).is_blunder());
+    }
+}
@@ -0,0 +1,247 @@
+use crate::model::board::{check_square_is_on_board, Board};
+use crate::model::game_error::GameError;
+use crate::model::game_error::GameError::{PieceNotAvailable, SquareIsEmpty, UnknownError};
+use crate::model::game_instance::GameInstance;
+use crate::model::game_move::Move;
+use crate::model::game_state::{GameState, PieceState};
+use crate::model::piece_size::PieceSize;
+use crate::model::player::Color;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct AppliedMove {
+    pub color: Color,
+    pub size: PieceSize,
+    pub origin: Option<(usize, usize)>,
+    pub destination: (usize, usize),
+    pub covered_piece: Option<(Color, PieceSize)>,
+    pub uncovered_piece: Option<(Color, PieceSize)>,
+    pub turn: u32,
+    #[cfg_attr(
+        feature = "serialization",
+        serde(
+            rename = "remaining_time_ms",
+            with = "crate::model::serialization::optional_duration_ms"
+        )
+    )]
+    pub remaining_time: Option<Duration>,
+    pub winner_color: Option<Color>,
+    pub draw: bool,
+}
+
+fn top_piece(board: &Board, (x, y): (usize, usize)) -> Option<(Color, PieceSize)> {
+    board.get_piece_color(x, y).zip(board.get_piece_size(x, y))
+}
+
+impl GameInstance {
+    pub fn apply_move(&mut self, game_move: Move) -> Result<AppliedMove, GameError> {
+        let (origin, destination) = match game_move {
+            Move::PutPiece(x, y, _) => (None, (x, y)),
+            Move::MovePiece(origin_x, origin_y, destination_x, destination_y) => {
+                (Some((origin_x, origin_y)), (destination_x, destination_y))
+            }
+        };
+        if let Some((origin_x, origin_y)) = origin {
+            check_square_is_on_board(origin_x, origin_y)?;
+        }
+        check_square_is_on_board(destination.0, destination.1)?;
+        let color = self.get_current_color();
+        let covered_piece = top_piece(self.get_board(), destination);
+
+        self.play(game_move)?;
+
+        let (_, size) = top_piece(self.get_board(), destination).ok_or(UnknownError)?;
+
+        Ok(AppliedMove {
+            color,
+            size,
+            origin,
+            destination,
+            covered_piece,
+            uncovered_piece: origin.and_then(|origin| top_piece(self.get_board(), origin)),
+            turn: self.get_turn(),
+            remaining_time: self.remaining_time(color),
+            winner_color: self.get_winner(),
+            draw: false,
+        })
+    }
+}
+
+impl GameState {
+    pub fn apply_move(&mut self, applied_move: &AppliedMove) -> Result<(), GameError> {
+        if let Some((x, y)) = applied_move.origin {
+            check_square_is_on_board(x, y)?;
+        }
+        check_square_is_on_board(applied_move.destination.0, applied_move.destination.1)?;
+        let player = self
+            .players
+            .iter_mut()
+            .find(|player| player.color == applied_move.color)
+            .ok_or(UnknownError)?;
+
+        let mut piece = match applied_move.origin {
+            Some((x, y)) => {
+                let mut piece = self.board.squares[x][y].take().ok_or_else(|| {
+                    SquareIsEmpty(String::from(
+                        "Impossible de bouger la pièce, la case est vide",
+                    ))
+                })?;
+                self.board.squares[x][y] =
+                    piece.nested_piece.take().map(|nested_piece| *nested_piece);
+                piece
+            }
+            None => {
+                let index = player
+                    .pieces
+                    .iter()
+                    .position(|piece_size| *piece_size == applied_move.size)
+                    .ok_or_else(|| {
+                        PieceNotAvailable(String::from(
+                            "Cette pièce n'est plus disponible dans la réserve",
+                        ))
+                    })?;
+                player.pieces.remove(index);
+                PieceState {
+                    color: applied_move.color,
+                    size: applied_move.size,
+                    nested_piece: None,
+                }
+            }
+        };
+
+        let (x, y) = applied_move.destination;
+        piece.nested_piece = self.board.squares[x][y].take().map(Box::new);
+        self.board.squares[x][y] = Some(piece);
+
+        player.remaining_time = applied_move.remaining_time;
+        self.turn = applied_move.turn;
+        self.current_color = applied_move.color.opponent();
+        self.winner_color = applied_move.winner_color;
+        self.draw = applied_move.draw;
+        Ok(())
+    }
+}
+
+impl Display for AppliedMove {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (x, y) = self.destination;
+        match self.origin {
+            Some((origin_x, origin_y)) => write!(
+                f,
+                "{} déplace une {} de ({}, {}) vers ({}, {})",
+                self.color, self.size, origin_x, origin_y, x, y
+            )?,
+            None => write!(f, "{} pose une {} en ({}, {})", self.color, self.size, x, y)?,
+        }
+
+        if let Some((color, size)) = self.covered_piece {
+            write!(f, ", recouvre une {} ({})", size, color)?;
+        }
+        if let Some((color, size)) = self.uncovered_piece {
+            write!(f, ", découvre une {} ({})", size, color)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::applied_move::AppliedMove;
+    use crate::model::game_instance::GameInstance;
+    use crate::model::game_move::Move;
+    use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+    use crate::model::player::Color::{Blue, Red};
+
+    #[test]
+    fn apply_move_describes_move_test() {
+        let mut game_instance = GameInstance::default();
+
+        let applied_move = game_instance
+            .apply_move(Move::PutPiece(1, 1, Small))
+            .unwrap();
+        assert_eq!(
+            applied_move,
+            AppliedMove {
+                color: Red,
+                size: Small,
+                origin: None,
+                destination: (1, 1),
+                covered_piece: None,
+                uncovered_piece: None,
+                turn: 1,
+                remaining_time: None,
+                winner_color: None,
+                draw: false,
+            }
+        );
+        assert_eq!(applied_move.to_string(), "Rouge pose une petite en (1, 1)");
+
+        let applied_move = game_instance
+            .apply_move(Move::PutPiece(1, 1, Medium))
+            .unwrap();
+        assert_eq!(applied_move.covered_piece, Some((Red, Small)));
+
+        game_instance.apply_move(Move::PutPiece(0, 0, Big)).unwrap();
+        let applied_move = game_instance
+            .apply_move(Move::MovePiece(1, 1, 0, 1))
+            .unwrap();
+        assert_eq!(applied_move.color, Blue);
+        assert_eq!(applied_move.size, Medium);
+        assert_eq!(applied_move.uncovered_piece, Some((Red, Small)));
+        assert_eq!(
+            applied_move.to_string(),
+            "Bleu déplace une moyenne de (1, 1) vers (0, 1), découvre une petite (Rouge)"
+        );
+
+        assert!(game_instance
+            .apply_move(Move::MovePiece(2, 2, 0, 0))
+            .is_err());
+        assert_eq!(game_instance.get_turn(), 4);
+    }
+
+    #[test]
+    fn game_state_apply_move_test() {
+        let mut game_instance = GameInstance::default();
+        let mut game_state = game_instance.to_game_state();
+
+        for game_move in [
+            Move::PutPiece(1, 1, Small),
+            Move::PutPiece(1, 1, Medium),
+            Move::PutPiece(0, 0, Big),
+            Move::MovePiece(1, 1, 0, 1),
+            Move::PutPiece(1, 1, Big),
+            Move::PutPiece(2, 2, Big),
+            Move::PutPiece(0, 2, Medium),
+            Move::MovePiece(0, 1, 2, 0),
+            Move::PutPiece(0, 1, Small),
+        ] {
+            let applied_move = game_instance.apply_move(game_move).unwrap();
+            game_state.apply_move(&applied_move).unwrap();
+            assert_eq!(game_state.to_string(), game_instance.to_string());
+            assert_eq!(game_state.winner_color, game_instance.get_winner());
+        }
+        assert_eq!(game_state.winner_color, Some(Red));
+
+        assert!(game_state
+            .apply_move(&AppliedMove {
+                color: Blue,
+                size: Small,
+                origin: Some((1, 2)),
+                destination: (0, 0),
+                covered_piece: None,
+                uncovered_piece: None,
+                turn: 10,
+                remaining_time: None,
+                winner_color: None,
+                draw: false,
+            })
+            .is_err());
+    }
+}
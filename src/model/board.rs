@@ -1,28 +1,55 @@
 use super::piece::Piece;
 use crate::model::game_error::GameError;
-use crate::model::game_error::GameError::{CannotPutPieceHere, SquareIsEmpty};
-use crate::model::game_state::BoardState;
+use crate::model::game_error::GameError::{CannotPutPieceHere, InvalidPosition, SquareIsEmpty};
+use crate::model::game_state::{BoardState, PieceState};
 use crate::model::piece_size::PieceSize;
 use crate::model::player::Color;
+use crate::model::symmetry::Symmetry;
 
+pub const BOARD_SIZE: usize = 3;
+
+#[derive(Default, Clone)]
 pub struct Board {
-    squares: [[Option<Piece>; 3]; 3],
+    squares: [[Option<Piece>; BOARD_SIZE]; BOARD_SIZE],
 }
 
-impl Default for Board {
-    fn default() -> Board {
-        Board {
-            squares: [[None, None, None], [None, None, None], [None, None, None]],
-        }
+pub fn check_square_is_on_board(x: usize, y: usize) -> Result<(), GameError> {
+    if x < BOARD_SIZE && y < BOARD_SIZE {
+        Ok(())
+    } else {
+        Err(CannotPutPieceHere(format!(
+            "La case ({}, {}) est en dehors du plateau",
+            x, y
+        )))
+    }
+}
+
+fn check_stack_is_valid(piece_state: &PieceState) -> Result<(), GameError> {
+    match &piece_state.nested_piece {
+        Some(nested_piece) if nested_piece.size >= piece_state.size => Err(InvalidPosition(
+            String::from("Les tailles d'une pile doivent être strictement croissantes"),
+        )),
+        Some(nested_piece) => check_stack_is_valid(nested_piece),
+        None => Ok(()),
     }
 }
 
 impl Board {
-    pub fn square_is_empty(&self, x: usize, y: usize) -> bool {
-        match self.squares[x][y] {
-            None => true,
-            _ => false,
+    pub fn from_board_state(board_state: &BoardState) -> Result<Board, GameError> {
+        for piece_state in board_state.squares.iter().flatten().flatten() {
+            check_stack_is_valid(piece_state)?;
         }
+
+        Ok(Board {
+            squares: board_state.squares.each_ref().map(|row| {
+                row.each_ref()
+                    .map(|square| square.as_ref().map(Piece::from_piece_state))
+            }),
+        })
+    }
+
+    pub fn square_is_empty(&self, x: usize, y: usize) -> bool {
+        self.squares[x][y].is_none()
     }
 
     pub fn square_is_not_empty(&self, x: usize, y: usize) -> bool {
@@ -46,17 +73,11 @@ impl Board {
     }
 
     pub fn get_piece_size(&self, x: usize, y: usize) -> Option<PieceSize> {
-        match &self.squares[x][y] {
-            None => None,
-            Some(piece) => Some((*piece).size.clone()),
-        }
+        self.squares[x][y].as_ref().map(|piece| piece.size)
     }
 
     pub fn get_piece_color(&self, x: usize, y: usize) -> Option<Color> {
-        match &self.squares[x][y] {
-            None => None,
-            Some(piece) => Some((*piece).color.clone()),
-        }
+        self.squares[x][y].as_ref().map(|piece| piece.color)
     }
 
     fn remove_piece(&mut self, x: usize, y: usize) -> Result<Piece, GameError> {
@@ -65,10 +86,9 @@ impl Board {
             Some(piece) => piece,
         };
 
-        let new_piece = match piece.remove_nested_piece() {
-            None => None,
-            Some(nested_piece) => Some(*nested_piece),
-        };
+        let new_piece = piece
+            .remove_nested_piece()
+            .map(|nested_piece| *nested_piece);
 
         self.squares[x][y] = new_piece;
         Ok(piece)
@@ -81,6 +101,18 @@ impl Board {
         destination_x: usize,
         destination_y: usize,
     ) -> Result<(), GameError> {
+        let piece_size = self
+            .get_piece_size(origin_x, origin_y)
+            .ok_or_else(|| SquareIsEmpty(String::from("Cette case est vide")))?;
+
+        if let Some(destination_piece_size) = self.get_piece_size(destination_x, destination_y) {
+            if destination_piece_size >= piece_size {
+                return Err(CannotPutPieceHere(String::from(
+                    "La pièce est trop petite pour être placer ici",
+                )));
+            }
+        }
+
         let piece = self.remove_piece(origin_x, origin_y)?;
         self.put_piece(destination_x, destination_y, piece)
     }
@@ -107,7 +139,7 @@ impl Board {
         match &self.squares[new_piece_x][new_piece_y] {
             None => false,
             Some(new_piece) => {
-                return before_piece.color == new_piece.color
+                before_piece.color == new_piece.color
                     && self.check_line_equals(new_piece_x, new_piece_y, x_add, y_add, new_piece)
             }
         }
@@ -118,7 +150,7 @@ impl Board {
             match &self.squares[x][0] {
                 None => continue,
                 Some(piece) => match self.check_line_equals(x, 0, 0, 1, piece) {
-                    true => return Some(piece.color.clone()),
+                    true => return Some(piece.color),
                     false => continue,
                 },
             }
@@ -132,7 +164,7 @@ impl Board {
             match &self.squares[0][y] {
                 None => continue,
                 Some(piece) => match self.check_line_equals(0, y, 1, 0, piece) {
-                    true => return Some(piece.color.clone()),
+                    true => return Some(piece.color),
                     false => continue,
                 },
             }
@@ -144,7 +176,7 @@ impl Board {
         let result = match &self.squares[0][0] {
             None => None,
             Some(piece) => match self.check_line_equals(0, 0, 1, 1, piece) {
-                true => return Some(piece.color.clone()),
+                true => return Some(piece.color),
                 false => None,
             },
         };
@@ -156,7 +188,7 @@ impl Board {
         match &self.squares[0][2] {
             None => None,
             Some(piece) => match self.check_line_equals(0, 2, 1, -1, piece) {
-                true => return Some(piece.color.clone()),
+                true => Some(piece.color),
                 false => None,
             },
         }
@@ -168,18 +200,28 @@ impl Board {
             .or(self.check_diagonal_win())
     }
 
-    pub fn to_board_state(&self) -> BoardState {
-        let mut squares = [[None, None, None], [None, None, None], [None, None, None]];
-
-        for x in 0..3 {
-            for y in 0..3 {
-                squares[x][y] = self.squares[x][y]
-                    .as_ref()
-                    .map(|piece| piece.to_piece_state());
-            }
+    pub fn transform(&self, symmetry: Symmetry) -> Board {
+        Board {
+            squares: symmetry.transform_squares(&self.squares),
         }
+    }
+
+    pub fn canonical_symmetry(&self) -> Symmetry {
+        self.to_board_state().canonical_symmetry()
+    }
+
+    pub fn canonical(&self) -> (Board, Symmetry) {
+        let symmetry = self.canonical_symmetry();
+        (self.transform(symmetry), symmetry)
+    }
 
-        BoardState { squares }
+    pub fn to_board_state(&self) -> BoardState {
+        BoardState {
+            squares: self.squares.each_ref().map(|row| {
+                row.each_ref()
+                    .map(|square| square.as_ref().map(|piece| piece.to_piece_state()))
+            }),
+        }
     }
 }
 
@@ -195,7 +237,7 @@ mod tests {
 
         for row in &board.squares {
             for case in row {
-                if let Some(_) = case {
+                if case.is_some() {
                     return Err(String::from("Le Plateau n'est pas correctement initialisé"));
                 }
             }
@@ -396,6 +438,23 @@ mod tests {
             .expect("Impossible de placer la pièce");
 
         match board.move_piece(0, 0, 1, 1) {
+            Err(CannotPutPieceHere(_)) => {
+                assert_eq!(board.get_piece_size(0, 0).unwrap(), Small);
+                Ok(())
+            }
+            _ => Err(()),
+        }
+    }
+
+    #[test]
+    fn move_a_piece_on_the_same_square_error_test() -> Result<(), ()> {
+        let mut board = Board::default();
+
+        board
+            .put_piece(0, 0, Piece::new(Small, Red))
+            .expect("Impossible de placer la pièce");
+
+        match board.move_piece(0, 0, 0, 0) {
             Err(CannotPutPieceHere(_)) => Ok(()),
             _ => Err(()),
         }
@@ -617,4 +676,40 @@ mod tests {
         assert_eq!(piece.color, Red);
         assert_eq!(piece.size, Medium);
     }
+
+    #[test]
+    fn board_from_board_state_test() {
+        let mut board = Board::default();
+        board
+            .put_piece(1, 1, Piece::new(Small, Blue))
+            .expect("Impossible de placer la pièce");
+        board
+            .put_piece(1, 1, Piece::new(Big, Red))
+            .expect("Impossible de placer la pièce");
+
+        let board_state = board.to_board_state();
+
+        assert_eq!(
+            Board::from_board_state(&board_state)
+                .unwrap()
+                .to_board_state(),
+            board_state
+        );
+    }
+
+    #[test]
+    fn board_from_invalid_stack_error_test() -> Result<(), ()> {
+        let mut board = Board::default();
+        board
+            .put_piece(1, 1, Piece::new(Medium, Blue))
+            .expect("Impossible de placer la pièce");
+        let mut board_state = board.to_board_state();
+        board_state.squares[1][1].as_mut().unwrap().nested_piece =
+            Some(Box::new(Piece::new(Medium, Red).to_piece_state()));
+
+        match Board::from_board_state(&board_state) {
+            Err(InvalidPosition(_)) => Ok(()),
+            _ => Err(()),
+        }
+    }
 }
@@ -0,0 +1,162 @@
+use crate::model::player::Color;
+use crate::model::player::Color::{Blue, Red};
+use std::time::{Duration, Instant};
+
+pub const CLOCK_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct TimeControl {
+    #[cfg_attr(
+        feature = "serialization",
+        serde(
+            rename = "base_time_ms",
+            with = "crate::model::serialization::duration_ms"
+        )
+    )]
+    pub base_time: Duration,
+    #[cfg_attr(
+        feature = "serialization",
+        serde(
+            rename = "increment_ms",
+            with = "crate::model::serialization::duration_ms"
+        )
+    )]
+    pub increment: Duration,
+}
+
+impl TimeControl {
+    pub fn new(base_time: Duration, increment: Duration) -> TimeControl {
+        TimeControl {
+            base_time,
+            increment,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Clock {
+    time_control: TimeControl,
+    remaining_times: [Duration; 2],
+    running_color: Color,
+    turn_started_at: Instant,
+}
+
+fn color_index(color: Color) -> usize {
+    match color {
+        Red => 0,
+        Blue => 1,
+    }
+}
+
+impl Clock {
+    pub fn new(time_control: TimeControl, running_color: Color, now: Instant) -> Clock {
+        Clock {
+            time_control,
+            remaining_times: [time_control.base_time, time_control.base_time],
+            running_color,
+            turn_started_at: now,
+        }
+    }
+
+    pub fn get_time_control(&self) -> TimeControl {
+        self.time_control
+    }
+
+    pub fn get_running_color(&self) -> Color {
+        self.running_color
+    }
+
+    pub fn remaining_time(&self, color: Color, now: Instant) -> Duration {
+        let remaining_time = self.remaining_times[color_index(color)];
+
+        if color == self.running_color {
+            remaining_time.saturating_sub(now.saturating_duration_since(self.turn_started_at))
+        } else {
+            remaining_time
+        }
+    }
+
+    pub fn is_flagged(&self, color: Color, now: Instant) -> bool {
+        self.remaining_time(color, now).is_zero()
+    }
+
+    pub fn press(&mut self, now: Instant) {
+        let remaining_time = self.remaining_time(self.running_color, now);
+        self.remaining_times[color_index(self.running_color)] =
+            remaining_time + self.time_control.increment;
+        self.running_color = self.running_color.opponent();
+        self.turn_started_at = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::clock::{Clock, TimeControl};
+    use crate::model::player::Color::{Blue, Red};
+    use std::time::{Duration, Instant};
+
+    fn time_control() -> TimeControl {
+        TimeControl::new(Duration::from_secs(60), Duration::from_secs(2))
+    }
+
+    #[test]
+    fn clock_new_test() {
+        let now = Instant::now();
+        let clock = Clock::new(time_control(), Red, now);
+
+        assert_eq!(clock.get_running_color(), Red);
+        assert_eq!(clock.remaining_time(Red, now), Duration::from_secs(60));
+        assert_eq!(clock.remaining_time(Blue, now), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn clock_only_running_color_loses_time_test() {
+        let now = Instant::now();
+        let clock = Clock::new(time_control(), Red, now);
+        let later = now + Duration::from_secs(10);
+
+        assert_eq!(clock.remaining_time(Red, later), Duration::from_secs(50));
+        assert_eq!(clock.remaining_time(Blue, later), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn clock_press_test() {
+        let now = Instant::now();
+        let mut clock = Clock::new(time_control(), Red, now);
+        let later = now + Duration::from_secs(10);
+
+        clock.press(later);
+
+        assert_eq!(clock.get_running_color(), Blue);
+        assert_eq!(clock.remaining_time(Red, later), Duration::from_secs(52));
+
+        let even_later = later + Duration::from_secs(5);
+
+        assert_eq!(
+            clock.remaining_time(Red, even_later),
+            Duration::from_secs(52)
+        );
+        assert_eq!(
+            clock.remaining_time(Blue, even_later),
+            Duration::from_secs(55)
+        );
+    }
+
+    #[test]
+    fn clock_flag_fall_test() {
+        let now = Instant::now();
+        let clock = Clock::new(time_control(), Red, now);
+
+        assert!(!clock.is_flagged(Red, now + Duration::from_secs(59)));
+        assert!(clock.is_flagged(Red, now + Duration::from_secs(60)));
+        assert!(!clock.is_flagged(Blue, now + Duration::from_secs(120)));
+        assert_eq!(
+            clock.remaining_time(Red, now + Duration::from_secs(120)),
+            Duration::ZERO
+        );
+    }
+}
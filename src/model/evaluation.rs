@@ -0,0 +1,89 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+pub(crate) const WIN_SCORE: i32 = 1_000_000;
+const MAX_PLIES: i32 = 1_000;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[cfg_attr(
+    feature = "serialization",
+    serde(tag = "type", content = "data", rename_all = "snake_case")
+)]
+pub enum Evaluation {
+    Win(u32),
+    Loss(u32),
+    Score(i32),
+}
+
+impl Evaluation {
+    pub fn from_score(score: i32) -> Evaluation {
+        if score > WIN_SCORE - MAX_PLIES {
+            Evaluation::Win((WIN_SCORE - score) as u32)
+        } else if score < -WIN_SCORE + MAX_PLIES {
+            Evaluation::Loss((WIN_SCORE + score) as u32)
+        } else {
+            Evaluation::Score(score)
+        }
+    }
+
+    pub fn to_score(self) -> i32 {
+        match self {
+            Evaluation::Win(plies) => WIN_SCORE - plies as i32,
+            Evaluation::Loss(plies) => -WIN_SCORE + plies as i32,
+            Evaluation::Score(score) => score,
+        }
+    }
+
+    pub fn from_opponent(self) -> Evaluation {
+        match self {
+            Evaluation::Win(plies) => Evaluation::Loss(plies + 1),
+            Evaluation::Loss(plies) => Evaluation::Win(plies + 1),
+            Evaluation::Score(score) => Evaluation::Score(-score),
+        }
+    }
+
+    pub fn is_win(&self) -> bool {
+        matches!(self, Evaluation::Win(_))
+    }
+
+    pub fn is_loss(&self) -> bool {
+        matches!(self, Evaluation::Loss(_))
+    }
+}
+
+impl Display for Evaluation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Evaluation::Win(plies) => write!(f, "gagnant en {}", plies),
+            Evaluation::Loss(plies) => write!(f, "perdant en {}", plies),
+            Evaluation::Score(score) => write!(f, "{:+}", score),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::evaluation::Evaluation;
+
+    #[test]
+    fn evaluation_score_round_trip_test() {
+        for evaluation in [
+            Evaluation::Win(3),
+            Evaluation::Loss(4),
+            Evaluation::Score(-12),
+        ] {
+            assert_eq!(Evaluation::from_score(evaluation.to_score()), evaluation);
+        }
+    }
+
+    #[test]
+    fn evaluation_from_opponent_test() {
+        assert_eq!(Evaluation::Win(1).from_opponent(), Evaluation::Loss(2));
+        assert_eq!(Evaluation::Loss(0).from_opponent(), Evaluation::Win(1));
+        assert_eq!(Evaluation::Score(5).from_opponent(), Evaluation::Score(-5));
+    }
+}
@@ -1,54 +1,138 @@
+#[cfg(feature = "ai")]
+use crate::ai::analysis::Analyzer;
+#[cfg(feature = "ai")]
+use crate::ai::minimax::Minimax;
+#[cfg(feature = "ai")]
+use crate::ai::strategy::Strategy;
+use crate::model::applied_move::AppliedMove;
+use crate::model::clock::TimeControl;
 use crate::model::game::GameCommand::*;
-use crate::model::game::GameEvent::{BoardUpdateEvent, GameErrorEvent, NewGameEvent};
+#[cfg(feature = "ai")]
+use crate::model::game::GameEvent::{AnalysisEvent, GameForfeitEvent, HintEvent};
+use crate::model::game::GameEvent::{
+    BoardUpdateEvent, GameErrorEvent, GameTimeoutEvent, MoveAppliedEvent, NewGameEvent,
+};
 use crate::model::game_command::GameCommand;
 use crate::model::game_command::GameCommand::MovePieceCommand;
 use crate::model::game_error::GameError;
-use crate::model::game_error::GameError::{CurrentlyNoGame, UnknownError};
+#[cfg(not(feature = "ai"))]
+use crate::model::game_error::GameError::UnsupportedCommand;
+use crate::model::game_error::GameError::{
+    CurrentlyNoGame, CurrentlyNoMatch, GameIsOver, UnknownError,
+};
 use crate::model::game_event::GameEvent;
 use crate::model::game_event::GameEvent::ExitEvent;
 use crate::model::game_instance::GameInstance;
+use crate::model::game_move::Move;
 use crate::model::game_state::GameState;
 use crate::model::listener::Listener;
 use crate::model::piece_size::PieceSize;
+use crate::model::player::Color;
 use std::sync::Arc;
 
+const REPETITION_DRAW_COUNT: usize = 3;
+
+#[derive(Default)]
 pub struct Game {
     game_instance: Option<GameInstance>,
-    listeners: Vec<Box<Arc<dyn Listener>>>,
-}
-impl Default for Game {
-    fn default() -> Self {
-        Game {
-            game_instance: None,
-            listeners: Vec::new(),
-        }
-    }
+    #[cfg(feature = "ai")]
+    initial_game_instance: Option<GameInstance>,
+    listeners: Vec<Arc<dyn Listener>>,
+    #[cfg(feature = "ai")]
+    bots: Vec<(Color, Box<dyn Strategy>)>,
+    first_color: Color,
+    repetition_draw: bool,
+    position_history: Vec<u64>,
 }
 
 impl Game {
-    fn new_game(&mut self) -> Result<GameState, GameError> {
-        self.game_instance = Some(GameInstance::default());
+    fn start_game(&mut self, game_instance: GameInstance) -> Result<GameState, GameError> {
+        #[cfg(feature = "ai")]
+        {
+            self.initial_game_instance = Some(game_instance.without_clock());
+        }
+        self.position_history = vec![game_instance.get_hash()];
+        self.game_instance = Some(game_instance);
         match &self.game_instance {
             Some(game_instance) => Ok(game_instance.to_game_state()),
             _ => Err(UnknownError),
         }
     }
 
+    fn new_game(&mut self, time_control: Option<TimeControl>) -> Result<GameState, GameError> {
+        let mut game_instance = GameInstance::with_first_color(self.first_color);
+
+        if let Some(time_control) = time_control {
+            game_instance.start_clock(time_control);
+        }
+
+        self.start_game(game_instance)
+    }
+
+    pub fn set_first_color(&mut self, first_color: Color) {
+        self.first_color = first_color;
+    }
+
+    pub fn get_winner(&self) -> Option<Color> {
+        self.game_instance
+            .as_ref()
+            .and_then(|game_instance| game_instance.get_winner())
+    }
+
+    pub fn set_repetition_draw(&mut self, repetition_draw: bool) {
+        self.repetition_draw = repetition_draw;
+    }
+
+    pub fn is_draw(&self) -> bool {
+        match self.game_instance {
+            Some(ref game_instance) if self.repetition_draw => {
+                game_instance.get_winner().is_none()
+                    && self
+                        .position_history
+                        .iter()
+                        .filter(|hash| **hash == game_instance.get_hash())
+                        .count()
+                        >= REPETITION_DRAW_COUNT
+            }
+            _ => false,
+        }
+    }
+
+    fn setup(&mut self, game_state: &GameState) -> Result<GameState, GameError> {
+        let game_instance = GameInstance::from_game_state(game_state)?;
+        self.start_game(game_instance)
+    }
+
+    fn play_move(&mut self, game_move: Move) -> Result<AppliedMove, GameError> {
+        if self.is_draw() {
+            return Err(GameIsOver(String::from(
+                "La partie est nulle par triple répétition de la position",
+            )));
+        }
+
+        let mut applied_move = match self.game_instance {
+            Some(ref mut game_instance) => game_instance.apply_move(game_move)?,
+            None => {
+                return Err(CurrentlyNoGame(String::from(
+                    "Il n'y a aucune partie en cours",
+                )))
+            }
+        };
+
+        if let Some(ref game_instance) = self.game_instance {
+            self.position_history.push(game_instance.get_hash());
+        }
+        applied_move.draw = self.is_draw();
+        Ok(applied_move)
+    }
+
     fn put_piece(
         &mut self,
         x: usize,
         y: usize,
         piece_size: PieceSize,
-    ) -> Result<GameState, GameError> {
-        match self.game_instance {
-            Some(ref mut game_instance) => {
-                game_instance.put_piece(x, y, piece_size)?;
-                Ok(game_instance.to_game_state())
-            }
-            None => Err(CurrentlyNoGame(String::from(
-                "Il n'y a aucune partie en cours",
-            ))),
-        }
+    ) -> Result<AppliedMove, GameError> {
+        self.play_move(Move::PutPiece(x, y, piece_size))
     }
 
     pub fn move_piece(
@@ -57,41 +141,202 @@ impl Game {
         origin_y: usize,
         destination_x: usize,
         destination_y: usize,
-    ) -> Result<GameState, GameError> {
-        match self.game_instance {
-            Some(ref mut game_instance) => {
-                game_instance.move_piece(origin_x, origin_y, destination_x, destination_y)?;
-                Ok(game_instance.to_game_state())
-            }
-            None => Err(CurrentlyNoGame(String::from(
-                "Il n'y a aucune partie en cours",
+    ) -> Result<AppliedMove, GameError> {
+        self.play_move(Move::MovePiece(
+            origin_x,
+            origin_y,
+            destination_x,
+            destination_y,
+        ))
+    }
+
+    pub fn get_game_state(&self) -> Option<GameState> {
+        self.game_instance.as_ref().map(|game_instance| GameState {
+            draw: self.is_draw(),
+            ..game_instance.to_game_state()
+        })
+    }
+
+    fn snapshot(&self) -> Result<GameState, GameError> {
+        self.get_game_state()
+            .ok_or_else(|| CurrentlyNoGame(String::from("Il n'y a aucune partie en cours")))
+    }
+
+    #[cfg(feature = "ai")]
+    fn hint(&self) -> Result<GameEvent, GameError> {
+        let game_instance = self
+            .game_instance
+            .as_ref()
+            .ok_or_else(|| CurrentlyNoGame(String::from("Il n'y a aucune partie en cours")))?;
+
+        if self.is_draw() {
+            return Err(GameIsOver(String::from(
+                "La partie est nulle par triple répétition de la position",
+            )));
+        }
+
+        let search_result = Minimax::default().search(game_instance);
+
+        match search_result.best_move {
+            Some(best_move) => Ok(HintEvent(best_move, search_result.evaluation)),
+            None => Err(GameError::GameIsOver(String::from(
+                "Aucun coup n'est possible dans cette partie",
             ))),
         }
     }
 
+    #[cfg(feature = "ai")]
+    fn analyze(&self) -> Result<GameEvent, GameError> {
+        let game_instance = self
+            .game_instance
+            .as_ref()
+            .ok_or_else(|| CurrentlyNoGame(String::from("Il n'y a aucune partie en cours")))?;
+
+        let initial_game_instance = self.initial_game_instance.as_ref().ok_or(UnknownError)?;
+
+        Analyzer::default()
+            .analyze(initial_game_instance, game_instance.get_history())
+            .map(AnalysisEvent)
+    }
+
+    #[cfg(not(feature = "ai"))]
+    fn hint(&self) -> Result<GameEvent, GameError> {
+        Err(UnsupportedCommand(String::from(
+            "Les conseils nécessitent la feature ai",
+        )))
+    }
+
+    #[cfg(not(feature = "ai"))]
+    fn analyze(&self) -> Result<GameEvent, GameError> {
+        Err(UnsupportedCommand(String::from(
+            "L'analyse de la partie nécessite la feature ai",
+        )))
+    }
+
+    #[cfg(feature = "ai")]
+    pub fn set_bot(&mut self, color: Color, strategy: Box<dyn Strategy>) {
+        self.bots.retain(|(bot_color, _)| *bot_color != color);
+        self.bots.push((color, strategy));
+    }
+
+    #[cfg(feature = "ai")]
+    pub fn remove_bot(&mut self, color: Color) {
+        self.bots.retain(|(bot_color, _)| *bot_color != color);
+    }
+
+    #[cfg(feature = "ai")]
+    fn play_bots(&mut self) {
+        loop {
+            if self.is_draw() {
+                return;
+            }
+            let game_instance = match self.game_instance {
+                Some(ref game_instance) if game_instance.get_winner().is_none() => game_instance,
+                _ => return,
+            };
+
+            let current_color = game_instance.get_current_color();
+            let strategy = match self
+                .bots
+                .iter_mut()
+                .find(|(bot_color, _)| *bot_color == current_color)
+            {
+                Some((_, strategy)) => strategy,
+                None => return,
+            };
+
+            let command_result = match strategy.choose_move(game_instance) {
+                Some(game_move) => self.play_move(game_move),
+                None => {
+                    self.forfeit(current_color);
+                    return;
+                }
+            };
+
+            match command_result.and_then(|applied_move| self.notify_move_applied(applied_move)) {
+                Ok(game_state) => self.notify_all(BoardUpdateEvent(game_state)),
+                Err(game_error) => {
+                    self.notify_all(GameErrorEvent(game_error));
+                    return;
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "ai")]
+    fn forfeit(&mut self, loser: Color) {
+        if let Some(ref mut game_instance) = self.game_instance {
+            game_instance.forfeit(loser);
+            self.notify_all(GameForfeitEvent(loser));
+        }
+    }
+
+    pub fn check_clock(&mut self) -> bool {
+        if self.is_draw() {
+            return false;
+        }
+
+        let timeout_loser = match self.game_instance {
+            Some(ref mut game_instance) => game_instance
+                .clock_now()
+                .and_then(|now| game_instance.check_flag_fall(now)),
+            None => None,
+        };
+
+        if let Some(loser) = timeout_loser {
+            self.notify_all(GameTimeoutEvent(loser));
+        }
+
+        timeout_loser.is_some()
+    }
+
+    fn notify_move_applied(&self, applied_move: AppliedMove) -> Result<GameState, GameError> {
+        self.notify_all(MoveAppliedEvent(applied_move));
+        self.snapshot()
+    }
+
     pub fn execute(&mut self, game_command: GameCommand) -> bool {
+        let is_move = matches!(game_command, PutPieceCommand(..) | MovePieceCommand(..));
+        if is_move && self.check_clock() {
+            return false;
+        }
+
         let command_result = match game_command {
-            NewGameCommand => self.new_game().map(|game_state| NewGameEvent(game_state)),
+            NewGameCommand => self.new_game(None).map(NewGameEvent),
+            NewTimedGameCommand(time_control) => {
+                self.new_game(Some(time_control)).map(NewGameEvent)
+            }
+            SetupCommand(game_state) => self.setup(&game_state).map(NewGameEvent),
             PutPieceCommand(x, y, size) => self
                 .put_piece(x, y, size)
-                .map(|game_state| BoardUpdateEvent(game_state)),
+                .and_then(|applied_move| self.notify_move_applied(applied_move))
+                .map(BoardUpdateEvent),
             MovePieceCommand(origin_x, origin_y, destination_x, destination_y) => self
                 .move_piece(origin_x, origin_y, destination_x, destination_y)
-                .map(|game_state| BoardUpdateEvent(game_state)),
+                .and_then(|applied_move| self.notify_move_applied(applied_move))
+                .map(BoardUpdateEvent),
+            SnapshotCommand => self.snapshot().map(BoardUpdateEvent),
+            HintCommand => self.hint(),
+            AnalyzeCommand => self.analyze(),
+            NewMatchCommand(_) | RematchCommand => Err(CurrentlyNoMatch(String::from(
+                "Les matchs doivent être lancés depuis un Match",
+            ))),
             ExitCommand => Ok(ExitEvent),
         };
 
-        let event = command_result.unwrap_or_else(|game_error| GameErrorEvent(game_error));
+        let event = command_result.unwrap_or_else(GameErrorEvent);
         self.notify_all(event.clone());
 
-        match event {
-            ExitEvent => true,
-            _ => false,
+        #[cfg(feature = "ai")]
+        if matches!(event, NewGameEvent(_)) || (is_move && matches!(event, BoardUpdateEvent(_))) {
+            self.play_bots();
         }
+
+        matches!(event, ExitEvent)
     }
 
-    pub fn subscribe<'b>(&'b mut self, listener: Arc<dyn Listener>) {
-        self.listeners.push(Box::from(listener));
+    pub fn subscribe(&mut self, listener: Arc<dyn Listener>) {
+        self.listeners.push(listener);
     }
 
     fn notify_all(&self, game_event: GameEvent) {
@@ -103,16 +348,37 @@ impl Game {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "ai")]
+    use crate::ai::minimax::Minimax;
+    #[cfg(feature = "ai")]
+    use crate::ai::strategy::Strategy;
+    use crate::model::clock::TimeControl;
     use crate::model::game::Game;
     use crate::model::game_command::GameCommand::{
-        ExitCommand, MovePieceCommand, NewGameCommand, PutPieceCommand,
+        AnalyzeCommand, ExitCommand, HintCommand, MovePieceCommand, NewGameCommand,
+        NewTimedGameCommand, PutPieceCommand, SetupCommand, SnapshotCommand,
+    };
+    #[cfg(not(feature = "ai"))]
+    use crate::model::game_error::GameError::UnsupportedCommand;
+    use crate::model::game_error::GameError::{
+        CannotPutPieceHere, CurrentlyNoGame, GameIsOver, InvalidPosition,
     };
     use crate::model::game_event::GameEvent;
-    use crate::model::game_event::GameEvent::{BoardUpdateEvent, ExitEvent, NewGameEvent};
+    #[cfg(feature = "ai")]
+    use crate::model::game_event::GameEvent::{AnalysisEvent, GameForfeitEvent, HintEvent};
+    use crate::model::game_event::GameEvent::{
+        BoardUpdateEvent, ExitEvent, GameErrorEvent, GameTimeoutEvent, MoveAppliedEvent,
+        NewGameEvent,
+    };
+    use crate::model::game_instance::GameInstance;
+    #[cfg(feature = "ai")]
+    use crate::model::game_move::Move;
     use crate::model::listener::Listener;
-    use crate::model::piece_size::PieceSize::{Medium, Small};
-    use crate::model::player::Color::Red;
+    use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+    use crate::model::player::Color::{Blue, Red};
     use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
 
     struct GameEventListenerMock {
         last_event: Mutex<Option<GameEvent>>,
@@ -125,6 +391,16 @@ mod tests {
         }
     }
 
+    struct GameEventsListenerMock {
+        events: Mutex<Vec<GameEvent>>,
+    }
+
+    impl Listener for GameEventsListenerMock {
+        fn notify(&self, game_event: GameEvent) {
+            self.events.lock().unwrap().push(game_event);
+        }
+    }
+
     #[test]
     fn game_new_test() {
         let game = Game::default();
@@ -158,10 +434,63 @@ mod tests {
         let last_event = arc.last_event.lock().unwrap().take();
 
         match last_event {
-            Some(event) => match event {
-                NewGameEvent(_) => Ok(()),
-                _ => Err(()),
-            },
+            Some(NewGameEvent(_)) => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    #[test]
+    fn out_of_board_command_test() -> Result<(), ()> {
+        let mut game = Game::default();
+        let game_listener = GameEventListenerMock {
+            last_event: Mutex::new(None),
+        };
+
+        let arc = Arc::from(game_listener);
+        game.subscribe(Arc::clone(&arc) as Arc<dyn Listener>);
+
+        game.execute(NewGameCommand);
+        game.execute(PutPieceCommand(5, 0, Small));
+        game.execute(MovePieceCommand(0, 0, 3, 3));
+
+        let last_event = arc.last_event.lock().unwrap().take();
+        match last_event {
+            Some(GameErrorEvent(CannotPutPieceHere(_))) => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    #[test]
+    fn repetition_draw_test() -> Result<(), ()> {
+        let mut game = Game::default();
+        let play_cycle = |game: &mut Game| {
+            game.execute(MovePieceCommand(0, 0, 0, 1));
+            game.execute(MovePieceCommand(2, 2, 2, 1));
+            game.execute(MovePieceCommand(0, 1, 0, 0));
+            game.execute(MovePieceCommand(2, 1, 2, 2));
+        };
+
+        game.execute(NewGameCommand);
+        game.execute(PutPieceCommand(0, 0, Big));
+        game.execute(PutPieceCommand(2, 2, Big));
+        play_cycle(&mut game);
+        play_cycle(&mut game);
+        assert!(!game.is_draw());
+        assert_eq!(game.get_game_state().unwrap().turn, 10);
+
+        game.set_repetition_draw(true);
+        game.execute(NewGameCommand);
+        game.execute(PutPieceCommand(0, 0, Big));
+        game.execute(PutPieceCommand(2, 2, Big));
+        play_cycle(&mut game);
+        assert!(!game.is_draw());
+        play_cycle(&mut game);
+        assert!(game.is_draw());
+        assert!(game.get_winner().is_none());
+        assert!(game.get_game_state().unwrap().draw);
+
+        match game.move_piece(0, 0, 0, 1) {
+            Err(GameIsOver(_)) => Ok(()),
             _ => Err(()),
         }
     }
@@ -236,6 +565,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn move_applied_event_test() {
+        let mut game = Game::default();
+        let game_listener = GameEventsListenerMock {
+            events: Mutex::new(Vec::new()),
+        };
+
+        let arc = Arc::from(game_listener);
+        game.subscribe(Arc::clone(&arc) as Arc<dyn Listener>);
+
+        game.execute(NewGameCommand);
+        game.execute(PutPieceCommand(0, 0, Small));
+        game.execute(PutPieceCommand(2, 2, Small));
+        game.execute(MovePieceCommand(0, 0, 1, 1));
+
+        let events = arc.events.lock().unwrap();
+        match &events[3..] {
+            [MoveAppliedEvent(put), BoardUpdateEvent(_), MoveAppliedEvent(moved), BoardUpdateEvent(game_state)] =>
+            {
+                assert_eq!(put.color, Blue);
+                assert_eq!(put.origin, None);
+                assert_eq!(put.destination, (2, 2));
+                assert_eq!(moved.origin, Some((0, 0)));
+                assert_eq!(moved.destination, (1, 1));
+                assert_eq!(moved.size, Small);
+                assert_eq!(moved.turn, game_state.turn);
+                assert!(game_state.board.squares[1][1].is_some());
+            }
+            _ => panic!("Événements inattendus : {:?}", events),
+        }
+    }
+
+    #[test]
+    fn snapshot_command_test() {
+        let mut game = Game::default();
+        let game_listener = GameEventListenerMock {
+            last_event: Mutex::new(None),
+        };
+
+        let arc = Arc::from(game_listener);
+        game.subscribe(Arc::clone(&arc) as Arc<dyn Listener>);
+
+        game.execute(SnapshotCommand);
+        assert!(matches!(
+            arc.last_event.lock().unwrap().take(),
+            Some(GameErrorEvent(CurrentlyNoGame(_)))
+        ));
+
+        game.execute(NewGameCommand);
+        game.execute(PutPieceCommand(1, 1, Small));
+        game.execute(SnapshotCommand);
+        assert!(matches!(
+            arc.last_event.lock().unwrap().take(),
+            Some(BoardUpdateEvent(game_state))
+                if game_state.turn == 1 && game_state.board.squares[1][1].is_some()
+        ));
+    }
+
     #[test]
     fn exit_command_test() -> Result<(), ()> {
         let mut game = Game::default();
@@ -255,10 +642,267 @@ mod tests {
         let last_event = arc.last_event.lock().unwrap().take();
 
         match last_event {
-            Some(event) => match event {
-                ExitEvent => Ok(()),
-                _ => Err(()),
-            },
+            Some(ExitEvent) => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    #[test]
+    fn timeout_event_test() -> Result<(), ()> {
+        let mut game = Game::default();
+        let game_listener = GameEventListenerMock {
+            last_event: Mutex::new(None),
+        };
+
+        let arc = Arc::from(game_listener);
+        game.subscribe(Arc::clone(&arc) as Arc<dyn Listener>);
+
+        game.execute(NewTimedGameCommand(TimeControl::new(
+            Duration::from_millis(1),
+            Duration::ZERO,
+        )));
+
+        thread::sleep(Duration::from_millis(5));
+        game.check_clock();
+
+        let last_event = arc.last_event.lock().unwrap().take();
+        match last_event {
+            Some(GameTimeoutEvent(Red)) => {}
+            _ => return Err(()),
+        }
+
+        game.execute(PutPieceCommand(0, 0, Small));
+
+        let last_event = arc.last_event.lock().unwrap().take();
+        match last_event {
+            Some(GameErrorEvent(GameIsOver(_))) => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    #[test]
+    fn timeout_during_move_test() {
+        let mut game = Game::default();
+        let game_listener = GameEventListenerMock {
+            last_event: Mutex::new(None),
+        };
+
+        let arc = Arc::from(game_listener);
+        game.subscribe(Arc::clone(&arc) as Arc<dyn Listener>);
+
+        game.execute(NewTimedGameCommand(TimeControl::new(
+            Duration::from_millis(1),
+            Duration::ZERO,
+        )));
+
+        thread::sleep(Duration::from_millis(5));
+        game.execute(PutPieceCommand(0, 0, Small));
+
+        assert!(matches!(
+            arc.last_event.lock().unwrap().take(),
+            Some(GameTimeoutEvent(Red))
+        ));
+    }
+
+    #[cfg(feature = "ai")]
+    #[test]
+    fn hint_command_test() -> Result<(), ()> {
+        let mut game = Game::default();
+        let game_listener = GameEventListenerMock {
+            last_event: Mutex::new(None),
+        };
+
+        let arc = Arc::from(game_listener);
+        game.subscribe(Arc::clone(&arc) as Arc<dyn Listener>);
+
+        game.execute(HintCommand);
+
+        match arc.last_event.lock().unwrap().take() {
+            Some(GameErrorEvent(CurrentlyNoGame(_))) => {}
+            _ => return Err(()),
+        }
+
+        game.execute(NewGameCommand);
+        game.execute(PutPieceCommand(0, 0, Small));
+        game.execute(HintCommand);
+
+        match arc.last_event.lock().unwrap().take() {
+            Some(HintEvent(_, _)) => {}
+            _ => return Err(()),
+        }
+
+        match game.game_instance {
+            Some(ref game_instance) if game_instance.to_game_state().turn == 1 => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    #[cfg(feature = "ai")]
+    #[test]
+    fn analyze_command_test() -> Result<(), ()> {
+        let mut game = Game::default();
+        let game_listener = GameEventListenerMock {
+            last_event: Mutex::new(None),
+        };
+
+        let arc = Arc::from(game_listener);
+        game.subscribe(Arc::clone(&arc) as Arc<dyn Listener>);
+
+        game.execute(NewGameCommand);
+        game.execute(PutPieceCommand(0, 0, Small));
+        game.execute(PutPieceCommand(1, 1, Medium));
+        game.execute(AnalyzeCommand);
+
+        let last_event = arc.last_event.lock().unwrap().take();
+        match last_event {
+            Some(AnalysisEvent(report)) if report.moves.len() == 2 => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    #[cfg(not(feature = "ai"))]
+    #[test]
+    fn unsupported_command_without_ai_test() -> Result<(), ()> {
+        let mut game = Game::default();
+        let game_listener = GameEventListenerMock {
+            last_event: Mutex::new(None),
+        };
+
+        let arc = Arc::from(game_listener);
+        game.subscribe(Arc::clone(&arc) as Arc<dyn Listener>);
+
+        game.execute(NewGameCommand);
+        for command in [HintCommand, AnalyzeCommand] {
+            game.execute(command);
+
+            let last_event = arc.last_event.lock().unwrap().take();
+            match last_event {
+                Some(GameErrorEvent(UnsupportedCommand(_))) => {}
+                _ => return Err(()),
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "ai")]
+    #[test]
+    fn bot_plays_after_human_test() -> Result<(), ()> {
+        let mut game = Game::default();
+        let game_listener = GameEventListenerMock {
+            last_event: Mutex::new(None),
+        };
+
+        let arc = Arc::from(game_listener);
+        game.subscribe(Arc::clone(&arc) as Arc<dyn Listener>);
+        game.set_bot(Blue, Box::new(Minimax::new(1)));
+
+        game.execute(NewGameCommand);
+        game.execute(PutPieceCommand(1, 1, Small));
+
+        let last_event = arc.last_event.lock().unwrap().take();
+        match last_event {
+            Some(BoardUpdateEvent(game_state)) if game_state.turn == 2 => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    #[cfg(feature = "ai")]
+    #[test]
+    fn bot_plays_first_test() {
+        let mut game = Game::default();
+        game.set_bot(Red, Box::new(Minimax::new(1)));
+
+        game.execute(NewGameCommand);
+
+        assert_eq!(game.game_instance.unwrap().to_game_state().turn, 1);
+    }
+
+    #[cfg(feature = "ai")]
+    struct ForfeitingStrategy;
+
+    #[cfg(feature = "ai")]
+    impl Strategy for ForfeitingStrategy {
+        fn choose_move(&mut self, _game_instance: &GameInstance) -> Option<Move> {
+            None
+        }
+    }
+
+    #[cfg(feature = "ai")]
+    #[test]
+    fn bot_forfeit_test() -> Result<(), ()> {
+        let mut game = Game::default();
+        let game_listener = GameEventListenerMock {
+            last_event: Mutex::new(None),
+        };
+
+        let arc = Arc::from(game_listener);
+        game.subscribe(Arc::clone(&arc) as Arc<dyn Listener>);
+        game.set_bot(Blue, Box::new(ForfeitingStrategy));
+
+        game.execute(NewGameCommand);
+        game.execute(PutPieceCommand(1, 1, Small));
+
+        assert_eq!(game.get_winner(), Some(Red));
+        let last_event = arc.last_event.lock().unwrap().take();
+        match last_event {
+            Some(GameForfeitEvent(Blue)) => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    #[cfg(feature = "ai")]
+    #[test]
+    fn setup_command_test() -> Result<(), ()> {
+        let mut game = Game::default();
+        let game_listener = GameEventListenerMock {
+            last_event: Mutex::new(None),
+        };
+
+        let arc = Arc::from(game_listener);
+        game.subscribe(Arc::clone(&arc) as Arc<dyn Listener>);
+
+        let mut game_instance = GameInstance::default();
+        game_instance.put_piece(1, 1, Small).unwrap();
+        let mut game_state = game_instance.to_game_state();
+        game_state.current_color = Red;
+
+        game.execute(SetupCommand(Box::new(game_state)));
+
+        let last_event = arc.last_event.lock().unwrap().take();
+        match last_event {
+            Some(NewGameEvent(game_state))
+                if game_state.current_color == Red && game_state.board.squares[1][1].is_some() => {}
+            _ => return Err(()),
+        }
+
+        game.execute(PutPieceCommand(1, 1, Medium));
+        game.execute(AnalyzeCommand);
+
+        let last_event = arc.last_event.lock().unwrap().take();
+        match last_event {
+            Some(AnalysisEvent(report)) if report.moves.len() == 1 => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    #[test]
+    fn setup_invalid_position_test() -> Result<(), ()> {
+        let mut game = Game::default();
+        let game_listener = GameEventListenerMock {
+            last_event: Mutex::new(None),
+        };
+
+        let arc = Arc::from(game_listener);
+        game.subscribe(Arc::clone(&arc) as Arc<dyn Listener>);
+
+        let mut game_state = GameInstance::default().to_game_state();
+        game_state.players[1].pieces.clear();
+
+        game.execute(SetupCommand(Box::new(game_state)));
+
+        let last_event = arc.last_event.lock().unwrap().take();
+        match last_event {
+            Some(GameErrorEvent(InvalidPosition(_))) if game.game_instance.is_none() => Ok(()),
             _ => Err(()),
         }
     }
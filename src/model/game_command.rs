@@ -1,8 +1,25 @@
+use crate::model::clock::TimeControl;
+use crate::model::game_state::GameState;
 use crate::model::piece_size::PieceSize;
 
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[cfg_attr(
+    feature = "serialization",
+    serde(tag = "type", content = "data", rename_all = "snake_case")
+)]
 pub enum GameCommand {
     NewGameCommand,
+    NewTimedGameCommand(TimeControl),
+    SetupCommand(Box<GameState>),
     PutPieceCommand(usize, usize, PieceSize),
     MovePieceCommand(usize, usize, usize, usize),
+    SnapshotCommand,
+    HintCommand,
+    AnalyzeCommand,
+    NewMatchCommand(u32),
+    RematchCommand,
     ExitCommand,
 }
@@ -1,9 +1,41 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[cfg_attr(
+    feature = "serialization",
+    serde(tag = "type", content = "data", rename_all = "snake_case")
+)]
 pub enum GameError {
     CurrentlyNoGame(String),
+    CurrentlyNoMatch(String),
     CannotPutPieceHere(String),
     SquareIsEmpty(String),
     PieceNotAvailable(String),
     NotYourPiece(String),
+    GameIsOver(String),
+    InvalidPosition(String),
+    UnsupportedCommand(String),
     UnknownError,
 }
+
+impl Display for GameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::CurrentlyNoGame(message)
+            | GameError::CurrentlyNoMatch(message)
+            | GameError::CannotPutPieceHere(message)
+            | GameError::SquareIsEmpty(message)
+            | GameError::PieceNotAvailable(message)
+            | GameError::NotYourPiece(message)
+            | GameError::GameIsOver(message)
+            | GameError::InvalidPosition(message)
+            | GameError::UnsupportedCommand(message) => write!(f, "{}", message),
+            GameError::UnknownError => write!(f, "Erreur inconnue"),
+        }
+    }
+}
@@ -1,12 +1,32 @@
+use crate::model::analysis_report::AnalysisReport;
+use crate::model::applied_move::AppliedMove;
+use crate::model::evaluation::Evaluation;
 use crate::model::game_error::GameError;
+use crate::model::game_match::MatchScore;
+use crate::model::game_move::Move;
 use crate::model::game_state::GameState;
 use crate::model::player::Color;
 
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[cfg_attr(
+    feature = "serialization",
+    serde(tag = "type", content = "data", rename_all = "snake_case")
+)]
 pub enum GameEvent {
     NewGameEvent(GameState),
     BoardUpdateEvent(GameState),
+    MoveAppliedEvent(AppliedMove),
     GameWinEvent(Color),
+    GameTimeoutEvent(Color),
+    GameForfeitEvent(Color),
     GameErrorEvent(GameError),
+    HintEvent(Move, Evaluation),
+    AnalysisEvent(AnalysisReport),
+    MatchUpdateEvent(MatchScore),
+    MatchEndEvent(MatchScore),
     ExitEvent,
 }
@@ -1,38 +1,248 @@
-use crate::model::board::Board;
+use crate::model::board::{check_square_is_on_board, Board};
+use crate::model::clock::{Clock, TimeControl};
 use crate::model::game_error::GameError;
-use crate::model::game_error::GameError::{CannotPutPieceHere, NotYourPiece, SquareIsEmpty};
+use crate::model::game_error::GameError::{
+    CannotPutPieceHere, GameIsOver, InvalidPosition, NotYourPiece, SquareIsEmpty, UnknownError,
+};
+use crate::model::game_move::Move;
 use crate::model::game_state::GameState;
 use crate::model::piece_size::PieceSize;
+use crate::model::piece_size::PieceSize::{Big, Medium, Small};
 use crate::model::player::Color::{Blue, Red};
-use crate::model::player::Player;
+use crate::model::player::{Color, Player};
+use crate::model::zobrist;
+use std::time::{Duration, Instant};
 
+#[derive(Clone)]
 pub struct GameInstance {
     board: Board,
     players: [Player; 2],
     turn: u32,
+    first_color: Color,
+    clock: Option<Clock>,
+    forfeit_loser: Option<Color>,
+    history: Vec<Move>,
+    hash: u64,
 }
 
 impl Default for GameInstance {
     fn default() -> Self {
-        GameInstance {
+        let mut game_instance = GameInstance {
             board: Board::default(),
             players: [Player::new(Red), Player::new(Blue)],
             turn: 0,
-        }
+            first_color: Red,
+            clock: None,
+            forfeit_loser: None,
+            history: Vec::new(),
+            hash: 0,
+        };
+        game_instance.reset_hash();
+        game_instance
     }
 }
 
 impl GameInstance {
+    pub fn with_first_color(first_color: Color) -> GameInstance {
+        let mut game_instance = GameInstance {
+            first_color,
+            ..GameInstance::default()
+        };
+        game_instance.reset_hash();
+        game_instance
+    }
+
+    pub fn with_time_control(time_control: TimeControl) -> GameInstance {
+        let mut game_instance = GameInstance::default();
+        game_instance.start_clock(time_control);
+        game_instance
+    }
+
+    pub fn from_game_state(game_state: &GameState) -> Result<GameInstance, GameError> {
+        let board = Board::from_board_state(&game_state.board)?;
+        let board_state = board.to_board_state();
+        let mut players = Vec::with_capacity(2);
+
+        for color in [Red, Blue] {
+            let mut matching_player_states = game_state
+                .players
+                .iter()
+                .filter(|player_state| player_state.color == color);
+
+            let player_state = match (matching_player_states.next(), matching_player_states.next())
+            {
+                (Some(player_state), None) => player_state,
+                _ => {
+                    return Err(InvalidPosition(format!(
+                        "La réserve du joueur {} doit être décrite une seule fois",
+                        color
+                    )))
+                }
+            };
+
+            for piece_size in [Small, Medium, Big] {
+                let reserve_count = player_state
+                    .pieces
+                    .iter()
+                    .filter(|reserve_piece_size| **reserve_piece_size == piece_size)
+                    .count();
+                let board_count = board_state.count_pieces(color, piece_size);
+
+                if reserve_count + board_count != 2 {
+                    return Err(InvalidPosition(format!(
+                        "Le joueur {} doit posséder exactement 2 pièces de taille {} (plateau : {}, réserve : {})",
+                        color, piece_size, board_count, reserve_count
+                    )));
+                }
+            }
+
+            players.push(Player::with_pieces(color, &player_state.pieces));
+        }
+
+        let blue_player = players.pop().ok_or(UnknownError)?;
+        let red_player = players.pop().ok_or(UnknownError)?;
+        let first_color = if game_state.turn.is_multiple_of(2) {
+            game_state.current_color
+        } else {
+            game_state.current_color.opponent()
+        };
+
+        let mut game_instance = GameInstance {
+            board,
+            players: [red_player, blue_player],
+            turn: game_state.turn,
+            first_color,
+            ..GameInstance::default()
+        };
+        game_instance.reset_hash();
+        Ok(game_instance)
+    }
+
+    pub fn start_clock(&mut self, time_control: TimeControl) {
+        self.clock = Some(Clock::new(
+            time_control,
+            self.get_current_color(),
+            Instant::now(),
+        ));
+    }
+
+    pub fn without_clock(&self) -> GameInstance {
+        GameInstance {
+            clock: None,
+            ..self.clone()
+        }
+    }
+
     pub fn next_turn(&mut self) {
         self.turn += 1;
     }
 
     pub fn get_current_player(&mut self) -> &mut Player {
-        return if self.turn % 2 == 0 {
+        let current_color = self.get_current_color();
+
+        if self.players[0].color == current_color {
             &mut self.players[0]
         } else {
             &mut self.players[1]
-        };
+        }
+    }
+
+    pub fn get_board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn get_turn(&self) -> u32 {
+        self.turn
+    }
+
+    pub fn remaining_time(&self, color: Color) -> Option<Duration> {
+        self.clock
+            .as_ref()
+            .zip(self.clock_now())
+            .map(|(clock, now)| clock.remaining_time(color, now))
+    }
+
+    pub fn get_current_color(&self) -> Color {
+        if self.turn.is_multiple_of(2) {
+            self.first_color
+        } else {
+            self.first_color.opponent()
+        }
+    }
+
+    pub fn get_winner(&self) -> Option<Color> {
+        self.board
+            .check_win()
+            .or(self.forfeit_loser.map(|loser| loser.opponent()))
+    }
+
+    pub fn check_flag_fall(&mut self, now: Instant) -> Option<Color> {
+        if self.forfeit_loser.is_some() || self.board.check_win().is_some() {
+            return None;
+        }
+
+        let clock = self.clock.as_ref()?;
+        let running_color = clock.get_running_color();
+
+        if clock.is_flagged(running_color, now) {
+            self.forfeit_loser = Some(running_color);
+        }
+
+        self.forfeit_loser
+    }
+
+    pub fn forfeit(&mut self, loser: Color) {
+        if self.get_winner().is_none() {
+            self.forfeit_loser = Some(loser);
+        }
+    }
+
+    pub fn clock_now(&self) -> Option<Instant> {
+        self.clock.as_ref().map(|_| Instant::now())
+    }
+
+    fn check_game_is_not_over(&mut self, now: Option<Instant>) -> Result<(), GameError> {
+        if let Some(now) = now {
+            self.check_flag_fall(now);
+        }
+
+        match self.get_winner() {
+            Some(_) => Err(GameIsOver(String::from("La partie est terminée"))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn get_hash(&self) -> u64 {
+        self.hash
+    }
+
+    fn reset_hash(&mut self) {
+        self.hash = self.compute_hash();
+    }
+
+    pub fn compute_hash(&self) -> u64 {
+        zobrist::compute_hash(
+            &self.board.to_board_state(),
+            &self
+                .players
+                .each_ref()
+                .map(|player| player.to_player_state()),
+            self.get_current_color(),
+        )
+    }
+
+    pub fn get_history(&self) -> &[Move] {
+        &self.history
+    }
+
+    fn end_turn(&mut self, game_move: Move, now: Option<Instant>) {
+        if let (Some(clock), Some(now)) = (self.clock.as_mut(), now) {
+            clock.press(now);
+        }
+        self.history.push(game_move);
+        self.next_turn();
+        self.hash ^= zobrist::side_to_move_key();
+        debug_assert_eq!(self.hash, self.compute_hash());
     }
 
     fn check_piece_can_be_nested(
@@ -61,11 +271,19 @@ impl GameInstance {
         y: usize,
         piece_size: PieceSize,
     ) -> Result<(), GameError> {
+        check_square_is_on_board(x, y)?;
+        let now = self.clock_now();
+        self.check_game_is_not_over(now)?;
         self.check_piece_can_be_nested(x, y, piece_size)?;
         let current_player = self.get_current_player();
+        let color = current_player.color;
+        let reserve_count = current_player.count_pieces(piece_size);
         let piece = current_player.remove_piece(piece_size)?;
         self.board.put_piece(x, y, piece)?;
-        self.next_turn();
+        self.hash ^= zobrist::piece_key(x, y, color, piece_size)
+            ^ zobrist::reserve_key(color, piece_size, reserve_count)
+            ^ zobrist::reserve_key(color, piece_size, reserve_count - 1);
+        self.end_turn(Move::PutPiece(x, y, piece_size), now);
         Ok(())
     }
 
@@ -76,6 +294,10 @@ impl GameInstance {
         destination_x: usize,
         destination_y: usize,
     ) -> Result<(), GameError> {
+        check_square_is_on_board(origin_x, origin_y)?;
+        check_square_is_on_board(destination_x, destination_y)?;
+        let now = self.clock_now();
+        self.check_game_is_not_over(now)?;
         let piece_color = self
             .board
             .get_piece_color(origin_x, origin_y)
@@ -92,31 +314,124 @@ impl GameInstance {
             )));
         }
 
+        let piece_size = self
+            .board
+            .get_piece_size(origin_x, origin_y)
+            .ok_or(UnknownError)?;
         self.board
-            .move_piece(origin_x, origin_y, destination_y, destination_x)?;
-        self.next_turn();
+            .move_piece(origin_x, origin_y, destination_x, destination_y)?;
+        self.hash ^= zobrist::piece_key(origin_x, origin_y, piece_color, piece_size)
+            ^ zobrist::piece_key(destination_x, destination_y, piece_color, piece_size);
+        self.end_turn(
+            Move::MovePiece(origin_x, origin_y, destination_x, destination_y),
+            now,
+        );
         Ok(())
     }
 
+    pub fn play(&mut self, game_move: Move) -> Result<(), GameError> {
+        match game_move {
+            Move::PutPiece(x, y, piece_size) => self.put_piece(x, y, piece_size),
+            Move::MovePiece(origin_x, origin_y, destination_x, destination_y) => {
+                self.move_piece(origin_x, origin_y, destination_x, destination_y)
+            }
+        }
+    }
+
+    fn can_cover(&self, x: usize, y: usize, piece_size: PieceSize) -> bool {
+        match self.board.get_piece_size(x, y) {
+            Some(current_piece_size) => current_piece_size < piece_size,
+            None => true,
+        }
+    }
+
+    pub fn legal_moves(&self) -> Vec<Move> {
+        let mut moves = Vec::new();
+
+        if self.get_winner().is_some() {
+            return moves;
+        }
+
+        let current_color = self.get_current_color();
+        let current_player = self
+            .players
+            .iter()
+            .find(|player| player.color == current_color)
+            .expect("Le joueur courant est introuvable");
+
+        for piece_size in current_player.get_available_piece_sizes() {
+            for x in 0..3 {
+                for y in 0..3 {
+                    if self.can_cover(x, y, piece_size) {
+                        moves.push(Move::PutPiece(x, y, piece_size));
+                    }
+                }
+            }
+        }
+
+        for origin_x in 0..3 {
+            for origin_y in 0..3 {
+                if self.board.get_piece_color(origin_x, origin_y) != Some(current_color) {
+                    continue;
+                }
+
+                let piece_size = match self.board.get_piece_size(origin_x, origin_y) {
+                    Some(piece_size) => piece_size,
+                    None => continue,
+                };
+
+                for destination_x in 0..3 {
+                    for destination_y in 0..3 {
+                        if self.can_cover(destination_x, destination_y, piece_size) {
+                            moves.push(Move::MovePiece(
+                                origin_x,
+                                origin_y,
+                                destination_x,
+                                destination_y,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        moves
+    }
+
     pub fn to_game_state(&self) -> GameState {
+        let now = self.clock_now();
+
         GameState {
-            players: [
-                self.players[0].to_player_state(),
-                self.players[1].to_player_state(),
-            ],
+            players: self.players.each_ref().map(|player| {
+                let mut player_state = player.to_player_state();
+                player_state.remaining_time = self
+                    .clock
+                    .as_ref()
+                    .zip(now)
+                    .map(|(clock, now)| clock.remaining_time(player.color, now));
+                player_state
+            }),
             board: self.board.to_board_state(),
             turn: self.turn,
-            winner_color: self.board.check_win(),
+            current_color: self.get_current_color(),
+            winner_color: self.get_winner(),
+            draw: false,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::model::game_error::GameError::{NotYourPiece, PieceNotAvailable, SquareIsEmpty};
+    use crate::model::clock::TimeControl;
+    use crate::model::game_error::GameError::{
+        CannotPutPieceHere, GameIsOver, InvalidPosition, NotYourPiece, PieceNotAvailable,
+        SquareIsEmpty,
+    };
     use crate::model::game_instance::GameInstance;
+    use crate::model::game_move::Move;
     use crate::model::piece_size::PieceSize::{Big, Medium, Small};
     use crate::model::player::Color::{Blue, Red};
+    use std::time::{Duration, Instant};
 
     #[test]
     fn game_instance_new_test() {
@@ -268,6 +583,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn out_of_board_square_test() {
+        let mut game_instance = GameInstance::default();
+
+        assert!(matches!(
+            game_instance.put_piece(5, 0, Small),
+            Err(CannotPutPieceHere(_))
+        ));
+        game_instance
+            .put_piece(0, 0, Small)
+            .expect("Impossible de placer la pièce");
+        assert!(matches!(
+            game_instance.move_piece(0, 3, 1, 1),
+            Err(CannotPutPieceHere(_))
+        ));
+        assert!(matches!(
+            game_instance.apply_move(Move::MovePiece(0, 0, 0, 7)),
+            Err(CannotPutPieceHere(_))
+        ));
+        assert_eq!(game_instance.get_turn(), 1);
+    }
+
     #[test]
     fn remove_piece_error_test() -> Result<(), ()> {
         let mut game_instance = GameInstance::default();
@@ -304,4 +641,343 @@ mod tests {
 
         assert_eq!(game_state.turn, 1);
     }
+
+    #[test]
+    fn put_piece_after_win_error_test() -> Result<(), ()> {
+        let mut game_instance = GameInstance::default();
+
+        game_instance.put_piece(0, 0, Small).unwrap();
+        game_instance.put_piece(1, 0, Small).unwrap();
+        game_instance.put_piece(0, 1, Medium).unwrap();
+        game_instance.put_piece(1, 1, Medium).unwrap();
+        game_instance.put_piece(0, 2, Big).unwrap();
+
+        assert_eq!(game_instance.get_winner(), Some(Red));
+
+        match game_instance.put_piece(1, 2, Big) {
+            Err(GameIsOver(_)) => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    #[test]
+    fn timed_game_instance_to_game_state_test() {
+        let game_instance = GameInstance::with_time_control(TimeControl::new(
+            Duration::from_secs(60),
+            Duration::from_secs(1),
+        ));
+
+        let game_state = game_instance.to_game_state();
+
+        for player_state in game_state.players {
+            let remaining_time = player_state.remaining_time.unwrap();
+            assert!(remaining_time <= Duration::from_secs(60));
+            assert!(remaining_time > Duration::from_secs(50));
+        }
+    }
+
+    #[test]
+    fn untimed_game_instance_never_flags_test() {
+        let mut game_instance = GameInstance::default();
+
+        assert!(game_instance
+            .check_flag_fall(Instant::now() + Duration::from_secs(3600))
+            .is_none());
+        assert!(game_instance.to_game_state().players[0]
+            .remaining_time
+            .is_none());
+    }
+
+    #[test]
+    fn put_piece_adds_increment_test() {
+        let mut game_instance = GameInstance::with_time_control(TimeControl::new(
+            Duration::from_secs(60),
+            Duration::from_secs(5),
+        ));
+
+        game_instance
+            .put_piece(1, 1, Small)
+            .expect("Impossible de placer la pièce");
+
+        let game_state = game_instance.to_game_state();
+        assert!(game_state.players[0].remaining_time.unwrap() > Duration::from_secs(60));
+    }
+
+    #[test]
+    fn flag_fall_test() -> Result<(), ()> {
+        let mut game_instance = GameInstance::with_time_control(TimeControl::new(
+            Duration::from_secs(60),
+            Duration::ZERO,
+        ));
+
+        game_instance
+            .put_piece(1, 1, Small)
+            .expect("Impossible de placer la pièce");
+
+        assert!(game_instance.check_flag_fall(Instant::now()).is_none());
+        assert_eq!(
+            game_instance.check_flag_fall(Instant::now() + Duration::from_secs(61)),
+            Some(Blue)
+        );
+        assert_eq!(game_instance.get_winner(), Some(Red));
+        assert_eq!(game_instance.to_game_state().winner_color, Some(Red));
+
+        match game_instance.put_piece(0, 0, Small) {
+            Err(GameIsOver(_)) => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    #[test]
+    fn move_piece_to_another_column_test() {
+        let mut game_instance = GameInstance::default();
+
+        game_instance
+            .put_piece(0, 0, Medium)
+            .expect("Impossible de placer la pièce");
+
+        game_instance
+            .put_piece(2, 2, Small)
+            .expect("Impossible de placer la pièce");
+
+        game_instance
+            .move_piece(0, 0, 0, 2)
+            .expect("Impossible de déplacer la pièce");
+
+        assert!(game_instance.board.square_is_empty(0, 0));
+        assert!(game_instance.board.square_is_empty(2, 0));
+        assert_eq!(game_instance.board.get_piece_color(0, 2).unwrap(), Red);
+    }
+
+    #[test]
+    fn legal_moves_start_position_test() {
+        let game_instance = GameInstance::default();
+
+        let moves = game_instance.legal_moves();
+
+        assert_eq!(moves.len(), 27);
+        assert!(moves
+            .iter()
+            .all(|game_move| matches!(game_move, Move::PutPiece(_, _, _))));
+    }
+
+    #[test]
+    fn legal_moves_with_pieces_on_board_test() {
+        let mut game_instance = GameInstance::default();
+
+        game_instance.put_piece(1, 1, Medium).unwrap();
+        game_instance.put_piece(0, 0, Big).unwrap();
+
+        let moves = game_instance.legal_moves();
+
+        assert!(moves.contains(&Move::PutPiece(1, 1, Big)));
+        assert!(!moves.contains(&Move::PutPiece(1, 1, Medium)));
+        assert!(!moves.contains(&Move::PutPiece(0, 0, Big)));
+        assert!(moves.contains(&Move::MovePiece(1, 1, 2, 2)));
+        assert!(!moves.contains(&Move::MovePiece(1, 1, 1, 1)));
+        assert!(!moves.contains(&Move::MovePiece(1, 1, 0, 0)));
+        assert!(!moves.contains(&Move::MovePiece(0, 0, 2, 2)));
+
+        for game_move in moves {
+            let mut next_game_instance = game_instance.clone();
+            assert!(next_game_instance.play(game_move).is_ok());
+        }
+    }
+
+    #[test]
+    fn legal_moves_after_win_test() {
+        let mut game_instance = GameInstance::default();
+
+        game_instance.put_piece(0, 0, Small).unwrap();
+        game_instance.put_piece(1, 0, Small).unwrap();
+        game_instance.put_piece(0, 1, Medium).unwrap();
+        game_instance.put_piece(1, 1, Medium).unwrap();
+        game_instance.put_piece(0, 2, Big).unwrap();
+
+        assert!(game_instance.legal_moves().is_empty());
+    }
+
+    #[test]
+    fn history_test() {
+        let mut game_instance = GameInstance::default();
+
+        game_instance.put_piece(1, 1, Medium).unwrap();
+        assert!(game_instance.put_piece(1, 1, Small).is_err());
+        game_instance.put_piece(2, 2, Small).unwrap();
+        game_instance.move_piece(1, 1, 2, 2).unwrap();
+
+        assert_eq!(
+            game_instance.get_history(),
+            &[
+                Move::PutPiece(1, 1, Medium),
+                Move::PutPiece(2, 2, Small),
+                Move::MovePiece(1, 1, 2, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn hash_start_position_test() {
+        let game_instance = GameInstance::default();
+
+        assert_eq!(game_instance.get_hash(), game_instance.compute_hash());
+        assert_ne!(game_instance.get_hash(), 0);
+    }
+
+    #[test]
+    fn hash_transposition_test() {
+        let mut first_game_instance = GameInstance::default();
+        first_game_instance.put_piece(0, 0, Small).unwrap();
+        first_game_instance.put_piece(1, 1, Medium).unwrap();
+        first_game_instance.put_piece(2, 2, Big).unwrap();
+
+        let mut second_game_instance = GameInstance::default();
+        second_game_instance.put_piece(2, 2, Big).unwrap();
+        second_game_instance.put_piece(1, 1, Medium).unwrap();
+        second_game_instance.put_piece(0, 0, Small).unwrap();
+
+        assert_eq!(
+            first_game_instance.get_hash(),
+            second_game_instance.get_hash()
+        );
+    }
+
+    #[test]
+    fn hash_side_to_move_test() {
+        let mut first_game_instance = GameInstance::default();
+        first_game_instance.put_piece(0, 0, Big).unwrap();
+        first_game_instance.put_piece(2, 2, Small).unwrap();
+        first_game_instance.move_piece(0, 0, 0, 1).unwrap();
+
+        let mut second_game_instance = GameInstance::default();
+        second_game_instance.put_piece(0, 1, Big).unwrap();
+        second_game_instance.put_piece(2, 2, Small).unwrap();
+
+        assert_eq!(
+            first_game_instance.board.to_board_state(),
+            second_game_instance.board.to_board_state()
+        );
+        assert_ne!(
+            first_game_instance.get_hash(),
+            second_game_instance.get_hash()
+        );
+    }
+
+    #[test]
+    fn hash_uncover_nested_piece_test() {
+        let mut game_instance = GameInstance::default();
+        let start_hash = game_instance.get_hash();
+
+        game_instance.put_piece(1, 1, Small).unwrap();
+        game_instance.put_piece(1, 1, Big).unwrap();
+        let covered_hash = game_instance.get_hash();
+
+        game_instance.put_piece(0, 0, Medium).unwrap();
+        game_instance.move_piece(1, 1, 2, 2).unwrap();
+
+        assert_eq!(game_instance.board.get_piece_color(1, 1), Some(Red));
+        assert_eq!(game_instance.get_hash(), game_instance.compute_hash());
+        assert_ne!(game_instance.get_hash(), covered_hash);
+        assert_ne!(game_instance.get_hash(), start_hash);
+    }
+
+    #[test]
+    fn hash_after_failed_move_test() {
+        let mut game_instance = GameInstance::default();
+        game_instance.put_piece(1, 1, Big).unwrap();
+        let hash = game_instance.get_hash();
+
+        assert!(game_instance.put_piece(1, 1, Medium).is_err());
+        assert!(game_instance.move_piece(1, 1, 0, 0).is_err());
+
+        assert_eq!(game_instance.get_hash(), hash);
+        assert_eq!(game_instance.get_hash(), game_instance.compute_hash());
+    }
+
+    #[test]
+    fn from_game_state_round_trip_test() {
+        let mut game_instance = GameInstance::default();
+        game_instance.put_piece(1, 1, Small).unwrap();
+        game_instance.put_piece(1, 1, Big).unwrap();
+        game_instance.put_piece(0, 0, Medium).unwrap();
+
+        let game_state = game_instance.to_game_state();
+        let setup_game_instance = GameInstance::from_game_state(&game_state).unwrap();
+
+        assert_eq!(setup_game_instance.to_game_state().board, game_state.board);
+        assert_eq!(setup_game_instance.get_current_color(), Blue);
+        assert_eq!(setup_game_instance.turn, 3);
+        assert_eq!(setup_game_instance.get_hash(), game_instance.get_hash());
+        assert_eq!(
+            setup_game_instance.legal_moves(),
+            game_instance.legal_moves()
+        );
+    }
+
+    #[test]
+    fn from_game_state_side_to_move_test() {
+        let mut game_state = GameInstance::default().to_game_state();
+        game_state.current_color = Blue;
+
+        let mut game_instance = GameInstance::from_game_state(&game_state).unwrap();
+
+        assert_eq!(game_instance.get_current_color(), Blue);
+        game_instance.put_piece(1, 1, Small).unwrap();
+        assert_eq!(game_instance.board.get_piece_color(1, 1), Some(Blue));
+        assert_eq!(game_instance.get_current_color(), Red);
+        assert_eq!(game_instance.get_hash(), game_instance.compute_hash());
+    }
+
+    #[test]
+    fn from_game_state_missing_piece_error_test() -> Result<(), ()> {
+        let mut game_state = GameInstance::default().to_game_state();
+        game_state.players[0].pieces.pop();
+
+        match GameInstance::from_game_state(&game_state) {
+            Err(InvalidPosition(_)) => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    #[test]
+    fn from_game_state_extra_piece_error_test() -> Result<(), ()> {
+        let mut game_instance = GameInstance::default();
+        game_instance.put_piece(1, 1, Small).unwrap();
+
+        let mut game_state = game_instance.to_game_state();
+        game_state.players[0].pieces.push(Small);
+
+        match GameInstance::from_game_state(&game_state) {
+            Err(InvalidPosition(_)) => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    #[test]
+    fn from_game_state_duplicated_player_error_test() -> Result<(), ()> {
+        let mut game_state = GameInstance::default().to_game_state();
+        game_state.players[1].color = Red;
+
+        match GameInstance::from_game_state(&game_state) {
+            Err(InvalidPosition(_)) => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    #[test]
+    fn with_first_color_test() {
+        let mut game_instance = GameInstance::with_first_color(Blue);
+
+        assert_eq!(game_instance.get_current_color(), Blue);
+        assert_eq!(game_instance.get_hash(), game_instance.compute_hash());
+        assert!(game_instance.put_piece(0, 0, Small).is_ok());
+        assert_eq!(
+            game_instance.to_game_state().board.squares[0][0]
+                .as_ref()
+                .unwrap()
+                .color,
+            Blue
+        );
+        assert_eq!(game_instance.get_current_color(), Red);
+    }
 }
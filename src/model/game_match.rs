@@ -0,0 +1,428 @@
+#[cfg(feature = "ai")]
+use crate::ai::strategy::Strategy;
+use crate::model::game::Game;
+use crate::model::game_command::GameCommand;
+use crate::model::game_command::GameCommand::{NewGameCommand, NewMatchCommand, RematchCommand};
+use crate::model::game_error::GameError;
+use crate::model::game_error::GameError::CurrentlyNoMatch;
+use crate::model::game_event::GameEvent;
+use crate::model::game_event::GameEvent::{GameErrorEvent, MatchEndEvent, MatchUpdateEvent};
+use crate::model::listener::Listener;
+use crate::model::player::Color;
+use crate::model::player::Color::{Blue, Red};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct MatchScore {
+    pub games_count: u32,
+    pub red_wins: u32,
+    pub blue_wins: u32,
+    pub draws: u32,
+}
+
+impl MatchScore {
+    pub fn new(games_count: u32) -> MatchScore {
+        MatchScore {
+            games_count,
+            red_wins: 0,
+            blue_wins: 0,
+            draws: 0,
+        }
+    }
+
+    pub fn games_played(&self) -> u32 {
+        self.red_wins + self.blue_wins + self.draws
+    }
+
+    pub fn record(&mut self, winner: Option<Color>) {
+        match winner {
+            Some(Red) => self.red_wins += 1,
+            Some(Blue) => self.blue_wins += 1,
+            None => self.draws += 1,
+        }
+    }
+
+    pub fn next_first_color(&self) -> Color {
+        if self.games_played().is_multiple_of(2) {
+            Red
+        } else {
+            Blue
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        let remaining_games = self.games_count.saturating_sub(self.games_played());
+        remaining_games == 0 || self.red_wins.abs_diff(self.blue_wins) > remaining_games
+    }
+
+    pub fn winner(&self) -> Option<Color> {
+        match self.red_wins.cmp(&self.blue_wins) {
+            std::cmp::Ordering::Greater => Some(Red),
+            std::cmp::Ordering::Less => Some(Blue),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+}
+
+impl Display for MatchScore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Match : {} {} - {} {} ({} nul(s), {}/{} parties)",
+            Red,
+            self.red_wins,
+            self.blue_wins,
+            Blue,
+            self.draws,
+            self.games_played(),
+            self.games_count
+        )?;
+
+        if self.is_over() {
+            match self.winner() {
+                Some(winner) => write!(f, " — victoire de {}", winner)?,
+                None => write!(f, " — match nul")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Match {
+    game: Game,
+    listeners: Vec<Arc<dyn Listener>>,
+    score: Option<MatchScore>,
+}
+
+impl Default for Match {
+    fn default() -> Self {
+        Match::new(Game::default())
+    }
+}
+
+impl Match {
+    pub fn new(mut game: Game) -> Match {
+        game.set_repetition_draw(true);
+        Match {
+            game,
+            listeners: Vec::new(),
+            score: None,
+        }
+    }
+
+    pub fn get_score(&self) -> Option<&MatchScore> {
+        self.score.as_ref()
+    }
+
+    #[cfg(feature = "ai")]
+    pub fn set_bot(&mut self, color: Color, strategy: Box<dyn Strategy>) {
+        self.game.set_bot(color, strategy);
+    }
+
+    fn new_match(&mut self, games_count: u32) -> Result<(), GameError> {
+        if games_count == 0 {
+            return Err(CurrentlyNoMatch(String::from(
+                "Un match doit comporter au moins une partie",
+            )));
+        }
+
+        self.score = Some(MatchScore::new(games_count));
+        self.start_next_game();
+        Ok(())
+    }
+
+    fn rematch(&mut self) -> Result<(), GameError> {
+        match &self.score {
+            Some(score) => self.new_match(score.games_count),
+            None => Err(CurrentlyNoMatch(String::from(
+                "Aucun match n'a encore été joué",
+            ))),
+        }
+    }
+
+    fn start_next_game(&mut self) -> bool {
+        let first_color = match &self.score {
+            Some(score) => score.next_first_color(),
+            None => Red,
+        };
+
+        self.game.set_first_color(first_color);
+        let exit = self.game.execute(NewGameCommand);
+        self.check_game_end();
+        exit
+    }
+
+    fn check_game_end(&mut self) {
+        let score = match self.score {
+            Some(ref mut score) if !score.is_over() => score,
+            _ => return,
+        };
+
+        let winner = self.game.get_winner();
+        if winner.is_none() && !self.game.is_draw() {
+            return;
+        }
+
+        score.record(winner);
+        let score = score.clone();
+        self.notify_all(MatchUpdateEvent(score.clone()));
+
+        if score.is_over() {
+            self.game.set_first_color(Red);
+            self.notify_all(MatchEndEvent(score));
+        } else {
+            self.start_next_game();
+        }
+    }
+
+    pub fn check_clock(&mut self) {
+        self.game.check_clock();
+        self.check_game_end();
+    }
+
+    pub fn execute(&mut self, game_command: GameCommand) -> bool {
+        let command_result = match game_command {
+            NewMatchCommand(games_count) => self.new_match(games_count),
+            RematchCommand => self.rematch(),
+            game_command => {
+                let exit = self.game.execute(game_command);
+                self.check_game_end();
+                return exit;
+            }
+        };
+
+        if let Err(game_error) = command_result {
+            self.notify_all(GameErrorEvent(game_error));
+        }
+
+        false
+    }
+
+    pub fn subscribe(&mut self, listener: Arc<dyn Listener>) {
+        self.game.subscribe(Arc::clone(&listener));
+        self.listeners.push(listener);
+    }
+
+    fn notify_all(&self, game_event: GameEvent) {
+        for listener in self.listeners.as_slice() {
+            listener.notify(game_event.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "ai")]
+    use crate::ai::minimax::Minimax;
+    #[cfg(feature = "ai")]
+    use crate::ai::random_strategy::RandomStrategy;
+    #[cfg(feature = "ai")]
+    use crate::model::game::Game;
+    use crate::model::game_command::GameCommand::{
+        MovePieceCommand, NewMatchCommand, PutPieceCommand, RematchCommand,
+    };
+    use crate::model::game_error::GameError::CurrentlyNoMatch;
+    use crate::model::game_event::GameEvent;
+    use crate::model::game_event::GameEvent::{
+        BoardUpdateEvent, GameErrorEvent, MatchEndEvent, MatchUpdateEvent, NewGameEvent,
+    };
+    use crate::model::game_match::{Match, MatchScore};
+    use crate::model::listener::Listener;
+    use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+    use crate::model::player::Color::{Blue, Red};
+    use std::sync::{Arc, Mutex};
+
+    struct GameEventListenerMock {
+        events: Mutex<Vec<GameEvent>>,
+    }
+
+    impl Listener for GameEventListenerMock {
+        fn notify(&self, game_event: GameEvent) {
+            self.events.lock().unwrap().push(game_event);
+        }
+    }
+
+    fn subscribe_mock(game_match: &mut Match) -> Arc<GameEventListenerMock> {
+        let listener = Arc::new(GameEventListenerMock {
+            events: Mutex::new(Vec::new()),
+        });
+        game_match.subscribe(Arc::clone(&listener) as Arc<dyn Listener>);
+        listener
+    }
+
+    fn win_game(game_match: &mut Match, first_x: usize) {
+        let second_x = (first_x + 1) % 3;
+        for (x, y, size) in [
+            (first_x, 0, Big),
+            (second_x, 0, Big),
+            (first_x, 1, Medium),
+            (second_x, 1, Medium),
+            (first_x, 2, Small),
+        ] {
+            game_match.execute(PutPieceCommand(x, y, size));
+        }
+    }
+
+    #[test]
+    fn match_score_test() {
+        let mut score = MatchScore::new(5);
+
+        assert_eq!(score.next_first_color(), Red);
+        assert!(!score.is_over());
+
+        score.record(Some(Blue));
+        assert_eq!(score.next_first_color(), Blue);
+        assert!(!score.is_over());
+
+        score.record(None);
+        score.record(Some(Blue));
+        assert_eq!(score.next_first_color(), Blue);
+        assert!(!score.is_over());
+
+        score.record(Some(Blue));
+        assert!(score.is_over());
+        assert_eq!(score.winner(), Some(Blue));
+        assert_eq!(score.games_played(), 4);
+    }
+
+    #[test]
+    fn match_score_tie_test() {
+        let mut score = MatchScore::new(2);
+        score.record(Some(Red));
+        score.record(Some(Blue));
+
+        assert!(score.is_over());
+        assert_eq!(score.winner(), None);
+    }
+
+    #[test]
+    fn match_alternates_first_color_test() {
+        let mut game_match = Match::default();
+        let listener = subscribe_mock(&mut game_match);
+
+        game_match.execute(NewMatchCommand(3));
+
+        let last_event = listener.events.lock().unwrap().last().cloned();
+        match last_event {
+            Some(NewGameEvent(game_state)) => assert_eq!(game_state.current_color, Red),
+            _ => panic!("Une nouvelle partie aurait dû commencer"),
+        }
+
+        win_game(&mut game_match, 0);
+
+        let events = listener.events.lock().unwrap();
+        match &events[events.len() - 3..] {
+            [BoardUpdateEvent(_), MatchUpdateEvent(score), NewGameEvent(game_state)] => {
+                assert_eq!(score.red_wins, 1);
+                assert_eq!(game_state.current_color, Blue);
+            }
+            _ => panic!("Le score du match aurait dû être annoncé"),
+        }
+    }
+
+    #[test]
+    fn repetition_draw_test() {
+        let mut game_match = Match::default();
+        let listener = subscribe_mock(&mut game_match);
+
+        game_match.execute(NewMatchCommand(3));
+        game_match.execute(PutPieceCommand(0, 0, Big));
+        game_match.execute(PutPieceCommand(2, 2, Big));
+        for _ in 0..2 {
+            game_match.execute(MovePieceCommand(0, 0, 0, 1));
+            game_match.execute(MovePieceCommand(2, 2, 2, 1));
+            game_match.execute(MovePieceCommand(0, 1, 0, 0));
+            game_match.execute(MovePieceCommand(2, 1, 2, 2));
+        }
+
+        let events = listener.events.lock().unwrap();
+        match &events[events.len() - 3..] {
+            [BoardUpdateEvent(drawn_game_state), MatchUpdateEvent(score), NewGameEvent(game_state)] =>
+            {
+                assert!(drawn_game_state.draw);
+                assert_eq!(score.draws, 1);
+                assert_eq!(game_state.current_color, Blue);
+            }
+            _ => panic!("La partie nulle aurait dû être comptée"),
+        }
+    }
+
+    #[test]
+    fn match_end_test() {
+        let mut game_match = Match::default();
+        let listener = subscribe_mock(&mut game_match);
+
+        game_match.execute(NewMatchCommand(3));
+        win_game(&mut game_match, 0);
+        win_game(&mut game_match, 1);
+
+        assert!(!listener
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|event| matches!(event, MatchEndEvent(_))));
+        assert_eq!(game_match.get_score().unwrap().games_played(), 2);
+        assert!(!game_match.get_score().unwrap().is_over());
+
+        win_game(&mut game_match, 2);
+
+        let last_event = listener.events.lock().unwrap().last().cloned();
+        match last_event {
+            Some(MatchEndEvent(score)) => {
+                assert_eq!(score.red_wins, 2);
+                assert_eq!(score.winner(), Some(Red));
+            }
+            _ => panic!("La fin du match aurait dû être annoncée"),
+        }
+    }
+
+    #[cfg(feature = "ai")]
+    #[test]
+    fn rematch_keeps_participants_test() {
+        let mut game = Game::default();
+        game.set_bot(Red, Box::new(Minimax::new(2)));
+        game.set_bot(Blue, Box::new(RandomStrategy::new(5)));
+        let mut game_match = Match::new(game);
+        let listener = subscribe_mock(&mut game_match);
+
+        game_match.execute(NewMatchCommand(2));
+        let first_score = game_match.get_score().unwrap().clone();
+        assert!(first_score.is_over());
+
+        game_match.execute(RematchCommand);
+        let second_score = game_match.get_score().unwrap();
+        assert!(second_score.is_over());
+        assert_eq!(second_score.games_count, 2);
+
+        let match_end_count = listener
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|event| matches!(event, MatchEndEvent(_)))
+            .count();
+        assert_eq!(match_end_count, 2);
+    }
+
+    #[test]
+    fn rematch_without_match_error_test() -> Result<(), ()> {
+        let mut game_match = Match::default();
+        let listener = subscribe_mock(&mut game_match);
+
+        game_match.execute(RematchCommand);
+
+        let last_event = listener.events.lock().unwrap().last().cloned();
+        match last_event {
+            Some(GameErrorEvent(CurrentlyNoMatch(_))) => Ok(()),
+            _ => Err(()),
+        }
+    }
+}
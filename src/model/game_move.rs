@@ -0,0 +1,45 @@
+use crate::model::game_command::GameCommand;
+use crate::model::game_command::GameCommand::{MovePieceCommand, PutPieceCommand};
+use crate::model::piece_size::PieceSize;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[cfg_attr(
+    feature = "serialization",
+    serde(tag = "type", content = "data", rename_all = "snake_case")
+)]
+pub enum Move {
+    PutPiece(usize, usize, PieceSize),
+    MovePiece(usize, usize, usize, usize),
+}
+
+impl Move {
+    pub fn to_game_command(&self) -> GameCommand {
+        match *self {
+            Move::PutPiece(x, y, piece_size) => PutPieceCommand(x, y, piece_size),
+            Move::MovePiece(origin_x, origin_y, destination_x, destination_y) => {
+                MovePieceCommand(origin_x, origin_y, destination_x, destination_y)
+            }
+        }
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Move::PutPiece(x, y, piece_size) => {
+                write!(f, "pose une {} en ({}, {})", piece_size, x, y)
+            }
+            Move::MovePiece(origin_x, origin_y, destination_x, destination_y) => write!(
+                f,
+                "déplace ({}, {}) vers ({}, {})",
+                origin_x, origin_y, destination_x, destination_y
+            ),
+        }
+    }
+}
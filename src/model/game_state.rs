@@ -1,20 +1,35 @@
 use crate::model::piece_size::PieceSize;
 use crate::model::player::Color;
+use std::time::Duration;
 
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct GameState {
     pub board: BoardState,
     pub players: [PlayerState; 2],
     pub turn: u32,
+    pub current_color: Color,
     pub winner_color: Option<Color>,
+    pub draw: bool,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct BoardState {
     pub squares: [[Option<PieceState>; 3]; 3],
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct PieceState {
     pub color: Color,
     pub size: PieceSize,
@@ -22,7 +37,38 @@ pub struct PieceState {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct PlayerState {
     pub color: Color,
     pub pieces: Vec<PieceSize>,
+    #[cfg_attr(
+        feature = "serialization",
+        serde(
+            rename = "remaining_time_ms",
+            with = "crate::model::serialization::optional_duration_ms"
+        )
+    )]
+    pub remaining_time: Option<Duration>,
+}
+
+impl BoardState {
+    pub fn count_pieces(&self, color: Color, piece_size: PieceSize) -> usize {
+        let mut count = 0;
+
+        for square in self.squares.iter().flatten() {
+            let mut piece_state = square.as_ref();
+
+            while let Some(current_piece_state) = piece_state {
+                if current_piece_state.color == color && current_piece_state.size == piece_size {
+                    count += 1;
+                }
+                piece_state = current_piece_state.nested_piece.as_deref();
+            }
+        }
+
+        count
+    }
 }
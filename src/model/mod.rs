@@ -1,11 +1,24 @@
+pub mod analysis_report;
+pub mod applied_move;
 pub mod board;
+pub mod clock;
+pub mod evaluation;
 pub mod game;
 pub mod game_command;
-mod game_error;
+pub mod game_error;
 pub mod game_event;
-mod game_instance;
-mod game_state;
+pub mod game_instance;
+pub mod game_match;
+pub mod game_move;
+pub mod game_state;
 pub mod listener;
+pub mod perft;
 mod piece;
 pub mod piece_size;
-mod player;
+pub mod player;
+pub mod position_string;
+#[cfg(feature = "serialization")]
+pub mod serialization;
+pub mod solution;
+pub mod symmetry;
+pub mod zobrist;
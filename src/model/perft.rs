@@ -0,0 +1,92 @@
+use crate::model::game_instance::GameInstance;
+use crate::model::game_move::Move;
+
+impl GameInstance {
+    pub fn perft(&self, depth: u32) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+
+        let moves = self.legal_moves();
+
+        if depth == 1 {
+            return moves.len() as u64;
+        }
+
+        moves
+            .into_iter()
+            .map(|game_move| self.perft_after(game_move, depth - 1))
+            .sum()
+    }
+
+    pub fn perft_divide(&self, depth: u32) -> Vec<(Move, u64)> {
+        if depth == 0 {
+            return Vec::new();
+        }
+
+        self.legal_moves()
+            .into_iter()
+            .map(|game_move| (game_move, self.perft_after(game_move, depth - 1)))
+            .collect()
+    }
+
+    fn perft_after(&self, game_move: Move, depth: u32) -> u64 {
+        let mut child = self.without_clock();
+        child.play(game_move).expect("Un coup légal a été refusé");
+        child.perft(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::game_instance::GameInstance;
+    use crate::model::game_move::Move;
+    use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+
+    const START_POSITION_PERFT: [(u32, u64); 5] =
+        [(0, 1), (1, 27), (2, 675), (3, 20_313), (4, 572_472)];
+
+    #[test]
+    fn perft_start_position_test() {
+        let game_instance = GameInstance::default();
+
+        for (depth, expected_count) in START_POSITION_PERFT {
+            assert_eq!(
+                game_instance.perft(depth),
+                expected_count,
+                "profondeur {}",
+                depth
+            );
+        }
+    }
+
+    #[test]
+    fn perft_divide_test() {
+        let game_instance = GameInstance::default();
+
+        let divide = game_instance.perft_divide(2);
+
+        assert_eq!(divide.len(), 27);
+        assert_eq!(
+            divide.iter().map(|(_, count)| count).sum::<u64>(),
+            game_instance.perft(2)
+        );
+        assert!(divide.contains(&(Move::PutPiece(1, 1, Small), 26)));
+        assert!(divide.contains(&(Move::PutPiece(1, 1, Medium), 25)));
+        assert!(divide.contains(&(Move::PutPiece(1, 1, Big), 24)));
+    }
+
+    #[test]
+    fn perft_finished_game_test() {
+        let mut game_instance = GameInstance::default();
+
+        game_instance.put_piece(0, 0, Small).unwrap();
+        game_instance.put_piece(1, 0, Small).unwrap();
+        game_instance.put_piece(0, 1, Medium).unwrap();
+        game_instance.put_piece(1, 1, Medium).unwrap();
+        game_instance.put_piece(0, 2, Big).unwrap();
+
+        assert_eq!(game_instance.perft(3), 0);
+        assert!(game_instance.perft_divide(3).is_empty());
+    }
+}
@@ -2,7 +2,7 @@ use crate::model::game_state::PieceState;
 use crate::model::piece_size::PieceSize;
 use crate::model::player::Color;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Piece {
     pub size: PieceSize,
     pub color: Color,
@@ -11,11 +11,11 @@ pub struct Piece {
 
 impl Piece {
     pub fn new(piece_size: PieceSize, color: Color) -> Piece {
-        return Piece {
+        Piece {
             size: piece_size,
             color,
             nested_piece: None,
-        };
+        }
     }
 
     pub fn remove_nested_piece(&mut self) -> Option<Box<Piece>> {
@@ -33,6 +33,17 @@ impl Piece {
         self.nested_piece = Some(Box::from(nested_piece));
     }
 
+    pub fn from_piece_state(piece_state: &PieceState) -> Piece {
+        Piece {
+            size: piece_state.size,
+            color: piece_state.color,
+            nested_piece: piece_state
+                .nested_piece
+                .as_ref()
+                .map(|nested_piece| Box::from(Piece::from_piece_state(nested_piece))),
+        }
+    }
+
     pub fn to_piece_state(&self) -> PieceState {
         PieceState {
             color: self.color,
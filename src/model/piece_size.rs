@@ -1,6 +1,24 @@
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[cfg_attr(feature = "serialization", serde(rename_all = "lowercase"))]
 pub enum PieceSize {
     Small,
     Medium,
     Big,
 }
+
+impl Display for PieceSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PieceSize::Small => write!(f, "petite"),
+            PieceSize::Medium => write!(f, "moyenne"),
+            PieceSize::Big => write!(f, "grande"),
+        }
+    }
+}
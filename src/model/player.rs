@@ -4,18 +4,58 @@ use crate::model::game_state::PlayerState;
 use crate::model::piece::Piece;
 use crate::model::piece_size::PieceSize;
 use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
+#[derive(Clone)]
 pub struct Player {
     pub color: Color,
     pieces: Vec<Piece>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[cfg_attr(feature = "serialization", serde(rename_all = "lowercase"))]
 pub enum Color {
+    #[default]
     Red,
     Blue,
 }
 
+impl Color {
+    pub fn opponent(&self) -> Color {
+        match self {
+            Color::Red => Color::Blue,
+            Color::Blue => Color::Red,
+        }
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Red => write!(f, "Rouge"),
+            Color::Blue => write!(f, "Bleu"),
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "rouge" | "red" => Ok(Color::Red),
+            "bleu" | "blue" => Ok(Color::Blue),
+            _ => Err(()),
+        }
+    }
+}
+
 impl Player {
     pub fn new(color: Color) -> Player {
         Player {
@@ -31,6 +71,16 @@ impl Player {
         }
     }
 
+    pub fn with_pieces(color: Color, piece_sizes: &[PieceSize]) -> Player {
+        let mut pieces: Vec<Piece> = piece_sizes
+            .iter()
+            .map(|piece_size| Piece::new(*piece_size, color))
+            .collect();
+        pieces.sort_by_key(|piece| piece.size);
+
+        Player { color, pieces }
+    }
+
     pub fn remove_piece(&mut self, piece_size: PieceSize) -> Result<Piece, GameError> {
         for i in 0..self.pieces.len() {
             if let Some(piece) = self.pieces.get(i) {
@@ -45,11 +95,26 @@ impl Player {
         )))
     }
 
+    pub fn count_pieces(&self, piece_size: PieceSize) -> usize {
+        self.pieces
+            .iter()
+            .filter(|piece| piece.size == piece_size)
+            .count()
+    }
+
+    pub fn get_available_piece_sizes(&self) -> Vec<PieceSize> {
+        let mut piece_sizes: Vec<PieceSize> = self.pieces.iter().map(|piece| piece.size).collect();
+        piece_sizes.sort();
+        piece_sizes.dedup();
+        piece_sizes
+    }
+
     pub fn to_player_state(&self) -> PlayerState {
-        return PlayerState {
+        PlayerState {
             color: self.color,
             pieces: self.pieces.iter().map(|piece| piece.size).collect(),
-        };
+            remaining_time: None,
+        }
     }
 }
 
@@ -57,8 +122,8 @@ impl Player {
 mod tests {
     use crate::model::game_error::GameError::PieceNotAvailable;
     use crate::model::piece_size::PieceSize::{Big, Medium, Small};
-    use crate::model::player::Color::Red;
-    use crate::model::player::Player;
+    use crate::model::player::Color::{Blue, Red};
+    use crate::model::player::{Color, Player};
 
     #[test]
     fn init_player_test() {
@@ -80,6 +145,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn color_opponent_test() {
+        assert_eq!(Red.opponent(), Blue);
+        assert_eq!(Blue.opponent(), Red);
+    }
+
+    #[test]
+    fn color_from_str_test() {
+        assert_eq!("rouge".parse(), Ok(Red));
+        assert_eq!("Blue".parse(), Ok(Blue));
+        assert!("vert".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn player_with_pieces_test() {
+        let player = Player::with_pieces(Blue, &[Big, Small, Big]);
+
+        assert_eq!(player.color, Blue);
+        assert_eq!(player.count_pieces(Big), 2);
+        assert_eq!(player.get_available_piece_sizes(), vec![Small, Big]);
+    }
+
     #[test]
     fn remove_piece_test() {
         let mut player = Player::new(Red);
@@ -96,6 +183,7 @@ mod tests {
 
         assert_eq!(piece.size, Big);
         assert_eq!(player.pieces.len(), 3);
+        assert_eq!(player.count_pieces(Small), 1);
     }
 
     #[test]
@@ -111,6 +199,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn get_available_piece_sizes_test() {
+        let mut player = Player::new(Red);
+
+        assert_eq!(player.get_available_piece_sizes(), vec![Small, Medium, Big]);
+
+        player.remove_piece(Medium).unwrap();
+        player.remove_piece(Medium).unwrap();
+
+        assert_eq!(player.get_available_piece_sizes(), vec![Small, Big]);
+    }
+
     #[test]
     fn player_to_player_state_test() {
         let mut player = Player::new(Red);
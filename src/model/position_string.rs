@@ -0,0 +1,377 @@
+use crate::model::board::Board;
+use crate::model::game_error::GameError;
+use crate::model::game_error::GameError::InvalidPosition;
+use crate::model::game_instance::GameInstance;
+use crate::model::game_state::{BoardState, GameState, PieceState, PlayerState};
+use crate::model::piece_size::PieceSize;
+use crate::model::player::Color;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// Format : "<plateau> <réserve rouge> <réserve bleue> <trait> <tour>".
+// Le plateau liste les lignes x séparées par '/', et dans chaque ligne les cases y
+// séparées par ','. Une case décrit sa pile de bas en haut, les pièces rouges en
+// majuscules (S, M, B) et les bleues en minuscules (s, m, b). '-' désigne une case
+// ou une réserve vide, le trait est 'r' ou 'b'.
+// Exemple de position initiale : "-,-,-/-,-,-/-,-,- SSMMBB ssmmbb r 0".
+
+fn piece_char(color: Color, piece_size: PieceSize) -> char {
+    let piece_char = match piece_size {
+        PieceSize::Small => 's',
+        PieceSize::Medium => 'm',
+        PieceSize::Big => 'b',
+    };
+
+    match color {
+        Color::Red => piece_char.to_ascii_uppercase(),
+        Color::Blue => piece_char,
+    }
+}
+
+fn parse_piece_char(piece_char: char) -> Result<(Color, PieceSize), GameError> {
+    let color = if piece_char.is_ascii_uppercase() {
+        Color::Red
+    } else {
+        Color::Blue
+    };
+
+    let piece_size = match piece_char.to_ascii_lowercase() {
+        's' => PieceSize::Small,
+        'm' => PieceSize::Medium,
+        'b' => PieceSize::Big,
+        _ => {
+            return Err(InvalidPosition(format!(
+                "Pièce inconnue '{}' (attendu S, M, B, s, m ou b)",
+                piece_char
+            )))
+        }
+    };
+
+    Ok((color, piece_size))
+}
+
+fn write_stack(f: &mut Formatter<'_>, piece_state: &PieceState) -> fmt::Result {
+    if let Some(nested_piece) = &piece_state.nested_piece {
+        write_stack(f, nested_piece)?;
+    }
+    write!(f, "{}", piece_char(piece_state.color, piece_state.size))
+}
+
+fn write_reserve(f: &mut Formatter<'_>, player_state: Option<&PlayerState>) -> fmt::Result {
+    let mut piece_sizes = player_state
+        .map(|player_state| player_state.pieces.clone())
+        .unwrap_or_default();
+    piece_sizes.sort();
+
+    if piece_sizes.is_empty() {
+        return write!(f, "-");
+    }
+
+    for piece_size in piece_sizes {
+        let color = player_state
+            .map(|player_state| player_state.color)
+            .unwrap_or(Color::Red);
+        write!(f, "{}", piece_char(color, piece_size))?;
+    }
+
+    Ok(())
+}
+
+impl Display for GameState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (x, row) in self.board.squares.iter().enumerate() {
+            if x > 0 {
+                write!(f, "/")?;
+            }
+
+            for (y, square) in row.iter().enumerate() {
+                if y > 0 {
+                    write!(f, ",")?;
+                }
+
+                match square {
+                    Some(piece_state) => write_stack(f, piece_state)?,
+                    None => write!(f, "-")?,
+                }
+            }
+        }
+
+        for color in [Color::Red, Color::Blue] {
+            write!(f, " ")?;
+            write_reserve(
+                f,
+                self.players
+                    .iter()
+                    .find(|player_state| player_state.color == color),
+            )?;
+        }
+
+        let side_to_move = match self.current_color {
+            Color::Red => 'r',
+            Color::Blue => 'b',
+        };
+
+        write!(f, " {} {}", side_to_move, self.turn)
+    }
+}
+
+fn parse_square(square: &str) -> Result<Option<PieceState>, GameError> {
+    if square == "-" {
+        return Ok(None);
+    }
+
+    if square.is_empty() {
+        return Err(InvalidPosition(String::from(
+            "Case vide : utilisez '-' pour une case sans pièce",
+        )));
+    }
+
+    let mut piece_state: Option<PieceState> = None;
+
+    for piece_char in square.chars() {
+        let (color, size) = parse_piece_char(piece_char)?;
+        piece_state = Some(PieceState {
+            color,
+            size,
+            nested_piece: piece_state.map(Box::new),
+        });
+    }
+
+    Ok(piece_state)
+}
+
+fn parse_board(board: &str) -> Result<BoardState, GameError> {
+    let rows: Vec<&str> = board.split('/').collect();
+
+    if rows.len() != 3 {
+        return Err(InvalidPosition(format!(
+            "Le plateau doit contenir 3 lignes séparées par '/', {} trouvée(s)",
+            rows.len()
+        )));
+    }
+
+    let mut board_state = BoardState {
+        squares: Default::default(),
+    };
+
+    for (x, row) in rows.iter().enumerate() {
+        let squares: Vec<&str> = row.split(',').collect();
+
+        if squares.len() != 3 {
+            return Err(InvalidPosition(format!(
+                "La ligne {} doit contenir 3 cases séparées par ',', {} trouvée(s)",
+                x,
+                squares.len()
+            )));
+        }
+
+        for (y, square) in squares.iter().enumerate() {
+            board_state.squares[x][y] = parse_square(square)?;
+        }
+    }
+
+    Ok(board_state)
+}
+
+fn parse_reserve(reserve: &str, color: Color) -> Result<PlayerState, GameError> {
+    let mut pieces = Vec::new();
+
+    if reserve != "-" {
+        for piece_char in reserve.chars() {
+            let (piece_color, piece_size) = parse_piece_char(piece_char)?;
+
+            if piece_color != color {
+                return Err(InvalidPosition(format!(
+                    "La réserve du joueur {} contient une pièce de l'adversaire '{}'",
+                    color, piece_char
+                )));
+            }
+
+            pieces.push(piece_size);
+        }
+    }
+
+    Ok(PlayerState {
+        color,
+        pieces,
+        remaining_time: None,
+    })
+}
+
+impl FromStr for GameState {
+    type Err = GameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+
+        let [board, red_reserve, blue_reserve, side_to_move, turn] = fields[..] else {
+            return Err(InvalidPosition(format!(
+                "Une position doit contenir 5 champs (plateau, réserve rouge, réserve bleue, trait, tour), {} trouvé(s)",
+                fields.len()
+            )));
+        };
+
+        let board = parse_board(board)?;
+        let players = [
+            parse_reserve(red_reserve, Color::Red)?,
+            parse_reserve(blue_reserve, Color::Blue)?,
+        ];
+
+        let current_color = match side_to_move {
+            "r" => Color::Red,
+            "b" => Color::Blue,
+            _ => {
+                return Err(InvalidPosition(format!(
+                    "Trait inconnu '{}' (attendu 'r' ou 'b')",
+                    side_to_move
+                )))
+            }
+        };
+
+        let turn = turn
+            .parse()
+            .map_err(|_| InvalidPosition(format!("Numéro de tour invalide '{}'", turn)))?;
+        let winner_color = Board::from_board_state(&board)?.check_win();
+
+        Ok(GameState {
+            board,
+            players,
+            turn,
+            current_color,
+            winner_color,
+            draw: false,
+        })
+    }
+}
+
+impl Display for GameInstance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_game_state())
+    }
+}
+
+impl FromStr for GameInstance {
+    type Err = GameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        GameInstance::from_game_state(&s.parse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "ai")]
+    use crate::ai::random::Random;
+    use crate::model::game_error::GameError::InvalidPosition;
+    use crate::model::game_instance::GameInstance;
+    use crate::model::game_state::GameState;
+    use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+    use crate::model::player::Color::{Blue, Red};
+
+    const START_POSITION: &str = "-,-,-/-,-,-/-,-,- SSMMBB ssmmbb r 0";
+
+    #[test]
+    fn start_position_to_string_test() {
+        assert_eq!(GameInstance::default().to_string(), START_POSITION);
+    }
+
+    #[test]
+    fn position_with_nested_pieces_to_string_test() {
+        let mut game_instance = GameInstance::default();
+        game_instance.put_piece(1, 1, Small).unwrap();
+        game_instance.put_piece(1, 1, Medium).unwrap();
+        game_instance.put_piece(1, 1, Big).unwrap();
+        game_instance.put_piece(0, 2, Big).unwrap();
+
+        assert_eq!(
+            game_instance.to_string(),
+            "-,-,b/-,SmB,-/-,-,- SMMB ssmb r 4"
+        );
+    }
+
+    #[test]
+    fn parse_game_state_test() {
+        let game_state: GameState = "-,-,b/-,SmB,-/-,-,- SMB ssm b 4".parse().unwrap();
+
+        let top_piece = game_state.board.squares[1][1].as_ref().unwrap();
+        assert_eq!(top_piece.color, Red);
+        assert_eq!(top_piece.size, Big);
+
+        let nested_piece = top_piece.nested_piece.as_ref().unwrap();
+        assert_eq!(nested_piece.color, Blue);
+        assert_eq!(nested_piece.size, Medium);
+        assert_eq!(nested_piece.nested_piece.as_ref().unwrap().size, Small);
+
+        assert_eq!(game_state.players[0].pieces, vec![Small, Medium, Big]);
+        assert_eq!(game_state.players[1].pieces, vec![Small, Small, Medium]);
+        assert_eq!(game_state.current_color, Blue);
+        assert_eq!(game_state.turn, 4);
+        assert!(game_state.winner_color.is_none());
+    }
+
+    #[cfg(feature = "ai")]
+    #[test]
+    fn round_trip_test() {
+        let mut random = Random::new(11);
+
+        for _ in 0..50 {
+            let mut game_instance = GameInstance::default();
+
+            for _ in 0..12 {
+                let moves = game_instance.legal_moves();
+                if moves.is_empty() {
+                    break;
+                }
+                game_instance
+                    .play(moves[random.next_below(moves.len())])
+                    .unwrap();
+
+                let position = game_instance.to_string();
+                let parsed_game_instance: GameInstance = position.parse().unwrap();
+                let parsed_game_state: GameState = position.parse().unwrap();
+
+                assert_eq!(parsed_game_instance.to_string(), position);
+                assert_eq!(parsed_game_state.to_string(), position);
+                assert_eq!(parsed_game_instance.get_hash(), game_instance.get_hash());
+                assert_eq!(parsed_game_state.winner_color, game_instance.get_winner());
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_position_errors_test() {
+        for position in [
+            "",
+            "-,-,-/-,-,-/-,-,- SSMMBB ssmmbb r",
+            "-,-,-/-,-,- SSMMBB ssmmbb r 0",
+            "-,-,-/-,-/-,-,- SSMMBB ssmmbb r 0",
+            "-,-,-/-,,-/-,-,- SSMMBB ssmmbb r 0",
+            "-,-,-/-,X,-/-,-,- SSMMBB ssmmbb r 0",
+            "-,-,-/-,-,-/-,-,- SSMMBb ssmmbb r 0",
+            "-,-,-/-,-,-/-,-,- SSMMBB ssmmbb x 0",
+            "-,-,-/-,-,-/-,-,- SSMMBB ssmmbb r -1",
+            "-,-,-/-,Bs,-/-,-,- SSMM ssmmb b 1",
+        ] {
+            match position.parse::<GameState>() {
+                Err(InvalidPosition(_)) => {}
+                _ => panic!("La position \"{}\" aurait dû être refusée", position),
+            }
+        }
+    }
+
+    #[test]
+    fn inconsistent_position_errors_test() {
+        for position in [
+            "-,-,-/-,-,-/-,-,- SSMMB ssmmbb r 0",
+            "-,-,-/-,S,-/-,-,- SSMMBB ssmmbb b 1",
+            "-,-,-/-,sB,-/-,-,- SSMMB ssmmb b 1",
+        ] {
+            assert!(position.parse::<GameState>().is_ok());
+            match position.parse::<GameInstance>() {
+                Err(InvalidPosition(_)) => {}
+                _ => panic!("La position \"{}\" aurait dû être refusée", position),
+            }
+        }
+    }
+}
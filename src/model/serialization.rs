@@ -0,0 +1,265 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+pub const SCHEMA_VERSION: u32 = 2;
+
+#[derive(Serialize)]
+struct Envelope<'a, T> {
+    schema_version: u32,
+    payload: &'a T,
+}
+
+pub fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(&Envelope {
+        schema_version: SCHEMA_VERSION,
+        payload: value,
+    })
+    .expect("Impossible de sérialiser la valeur")
+}
+
+pub fn from_json<T: DeserializeOwned>(json: &str) -> Result<T, String> {
+    let mut envelope: Value =
+        serde_json::from_str(json).map_err(|error| format!("Message invalide : {}", error))?;
+    let schema_version = envelope
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| String::from("Message invalide : version de schéma absente"))?;
+
+    if schema_version != SCHEMA_VERSION as u64 {
+        return Err(format!(
+            "Version de schéma non supportée : {} (attendue : {})",
+            schema_version, SCHEMA_VERSION
+        ));
+    }
+
+    serde_json::from_value(envelope["payload"].take())
+        .map_err(|error| format!("Message invalide : {}", error))
+}
+
+pub(crate) mod duration_ms {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_millis() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_millis)
+    }
+}
+
+pub(crate) mod optional_duration_ms {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(
+        duration: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match duration {
+            Some(duration) => serializer.serialize_some(&(duration.as_millis() as u64)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Option::<u64>::deserialize(deserializer).map(|millis| millis.map(Duration::from_millis))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "ai")]
+    use crate::ai::analysis::Analyzer;
+    use crate::model::clock::TimeControl;
+    #[cfg(feature = "ai")]
+    use crate::model::evaluation::Evaluation;
+    use crate::model::game_command::GameCommand;
+    use crate::model::game_error::GameError;
+    use crate::model::game_event::GameEvent;
+    use crate::model::game_instance::GameInstance;
+    use crate::model::game_match::MatchScore;
+    use crate::model::game_move::Move;
+    use crate::model::game_state::{BoardState, GameState, PieceState, PlayerState};
+    use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+    use crate::model::player::Color::{Blue, Red};
+    use crate::model::serialization::{from_json, to_json};
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use std::time::Duration;
+
+    fn assert_round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+        let json = to_json(value);
+        let decoded: T = from_json(&json).unwrap();
+        assert_eq!(to_json(&decoded), json);
+        decoded
+    }
+
+    fn covered_game_state() -> GameState {
+        let mut game_instance = GameInstance::with_time_control(TimeControl::new(
+            Duration::from_secs(60),
+            Duration::ZERO,
+        ));
+        game_instance.put_piece(1, 1, Small).unwrap();
+        game_instance.put_piece(1, 1, Medium).unwrap();
+        game_instance.to_game_state()
+    }
+
+    #[test]
+    fn game_state_round_trip_test() {
+        let game_state = covered_game_state();
+
+        let decoded = assert_round_trip(&game_state);
+
+        assert_eq!(decoded.board, game_state.board);
+        assert_eq!(decoded.turn, 2);
+        assert!(decoded.players[0].remaining_time.is_some());
+        assert_eq!(decoded.to_string(), game_state.to_string());
+    }
+
+    #[test]
+    fn state_parts_round_trip_test() {
+        let game_state = covered_game_state();
+
+        let board_state: BoardState = assert_round_trip(&game_state.board);
+        assert_eq!(board_state, game_state.board);
+
+        let piece_state: PieceState = assert_round_trip(&PieceState {
+            color: Blue,
+            size: Big,
+            nested_piece: Some(Box::new(PieceState {
+                color: Red,
+                size: Small,
+                nested_piece: None,
+            })),
+        });
+        assert_eq!(piece_state.nested_piece.unwrap().color, Red);
+
+        let player_state: PlayerState = assert_round_trip(&game_state.players[1]);
+        assert_eq!(player_state.pieces, game_state.players[1].pieces);
+    }
+
+    #[test]
+    fn game_command_round_trip_test() {
+        let commands = vec![
+            GameCommand::NewGameCommand,
+            GameCommand::NewTimedGameCommand(TimeControl::new(
+                Duration::from_secs(300),
+                Duration::from_secs(2),
+            )),
+            GameCommand::SetupCommand(Box::new(covered_game_state())),
+            GameCommand::PutPieceCommand(0, 2, Big),
+            GameCommand::MovePieceCommand(0, 0, 2, 2),
+            GameCommand::SnapshotCommand,
+            GameCommand::HintCommand,
+            GameCommand::AnalyzeCommand,
+            GameCommand::NewMatchCommand(3),
+            GameCommand::RematchCommand,
+            GameCommand::ExitCommand,
+        ];
+
+        for command in &commands {
+            assert_round_trip(command);
+        }
+
+        assert_eq!(
+            to_json(&commands[3]),
+            r#"{"schema_version":2,"payload":{"type":"put_piece_command","data":[0,2,"big"]}}"#
+        );
+        assert!(to_json(&commands[1]).contains(r#""base_time_ms":300000,"increment_ms":2000"#));
+    }
+
+    #[test]
+    fn game_event_round_trip_test() {
+        let mut score = MatchScore::new(3);
+        score.record(Some(Blue));
+
+        let events = vec![
+            GameEvent::NewGameEvent(covered_game_state()),
+            GameEvent::BoardUpdateEvent(covered_game_state()),
+            GameEvent::MoveAppliedEvent(
+                GameInstance::default()
+                    .apply_move(Move::PutPiece(1, 1, Small))
+                    .unwrap(),
+            ),
+            GameEvent::GameWinEvent(Red),
+            GameEvent::GameTimeoutEvent(Blue),
+            GameEvent::GameForfeitEvent(Red),
+            GameEvent::GameErrorEvent(GameError::UnknownError),
+            GameEvent::MatchUpdateEvent(score.clone()),
+            GameEvent::MatchEndEvent(score),
+            GameEvent::ExitEvent,
+        ];
+
+        for event in &events {
+            assert_round_trip(event);
+        }
+
+        assert_eq!(
+            to_json(&events[3]),
+            r#"{"schema_version":2,"payload":{"type":"game_win_event","data":"red"}}"#
+        );
+    }
+
+    #[cfg(feature = "ai")]
+    #[test]
+    fn ai_round_trip_test() {
+        let mut game_instance = GameInstance::default();
+        game_instance.put_piece(0, 0, Big).unwrap();
+        let report = Analyzer::new(1)
+            .analyze(&GameInstance::default(), game_instance.get_history())
+            .unwrap();
+
+        assert_round_trip(&GameEvent::HintEvent(
+            Move::MovePiece(0, 0, 1, 1),
+            Evaluation::Win(3),
+        ));
+        assert_round_trip(&GameEvent::AnalysisEvent(report));
+    }
+
+    #[test]
+    fn game_error_round_trip_test() {
+        let errors = vec![
+            GameError::CurrentlyNoGame(String::from("a")),
+            GameError::CurrentlyNoMatch(String::from("b")),
+            GameError::CannotPutPieceHere(String::from("c")),
+            GameError::SquareIsEmpty(String::from("d")),
+            GameError::PieceNotAvailable(String::from("e")),
+            GameError::NotYourPiece(String::from("f")),
+            GameError::GameIsOver(String::from("g")),
+            GameError::InvalidPosition(String::from("h")),
+            GameError::UnsupportedCommand(String::from("i")),
+            GameError::UnknownError,
+        ];
+
+        for error in &errors {
+            assert_round_trip(error);
+        }
+
+        assert_eq!(
+            to_json(&errors[5]),
+            r#"{"schema_version":2,"payload":{"type":"not_your_piece","data":"f"}}"#
+        );
+    }
+
+    #[test]
+    fn schema_version_test() {
+        assert!(from_json::<GameError>(
+            r#"{"schema_version":1,"payload":{"type":"unknown_error"}}"#
+        )
+        .unwrap_err()
+        .contains("Version de schéma non supportée"));
+        assert!(from_json::<GameError>(r#"{"payload":{"type":"unknown_error"}}"#).is_err());
+        assert!(
+            from_json::<GameError>(r#"{"schema_version":2,"payload":{"type":"inconnu"}}"#).is_err()
+        );
+        assert!(matches!(
+            from_json::<GameError>(r#"{"schema_version":2,"payload":{"type":"unknown_error"}}"#),
+            Ok(GameError::UnknownError)
+        ));
+    }
+}
@@ -0,0 +1,92 @@
+use crate::model::game_move::Move;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[cfg_attr(feature = "serialization", serde(rename_all = "snake_case"))]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub fn from_opponent(self) -> Outcome {
+        match self {
+            Outcome::Loss => Outcome::Win,
+            Outcome::Draw => Outcome::Draw,
+            Outcome::Win => Outcome::Loss,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct Solution {
+    pub outcome: Outcome,
+    pub plies: u32,
+    pub best_move: Option<Move>,
+}
+
+impl Solution {
+    pub fn from_opponent(self) -> Solution {
+        Solution {
+            outcome: self.outcome.from_opponent(),
+            plies: match self.outcome {
+                Outcome::Draw => 0,
+                _ => self.plies + 1,
+            },
+            best_move: None,
+        }
+    }
+}
+
+impl Display for Solution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.outcome {
+            Outcome::Win => write!(f, "gagnant en {}", self.plies),
+            Outcome::Draw => write!(f, "nul"),
+            Outcome::Loss => write!(f, "perdant en {}", self.plies),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::solution::{Outcome, Solution};
+
+    #[test]
+    fn outcome_order_test() {
+        assert!(Outcome::Loss < Outcome::Draw);
+        assert!(Outcome::Draw < Outcome::Win);
+        assert_eq!(Outcome::Win.from_opponent(), Outcome::Loss);
+        assert_eq!(Outcome::Draw.from_opponent(), Outcome::Draw);
+    }
+
+    #[test]
+    fn solution_from_opponent_test() {
+        let solution = Solution {
+            outcome: Outcome::Win,
+            plies: 3,
+            best_move: None,
+        };
+
+        assert_eq!(
+            solution.from_opponent(),
+            Solution {
+                outcome: Outcome::Loss,
+                plies: 4,
+                best_move: None,
+            }
+        );
+        assert_eq!(solution.to_string(), "gagnant en 3");
+        assert_eq!(solution.from_opponent().to_string(), "perdant en 4");
+    }
+}
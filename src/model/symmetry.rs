@@ -0,0 +1,274 @@
+use crate::model::game_move::Move;
+use crate::model::game_state::{BoardState, PieceState};
+use crate::model::player::Color;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Symmetry {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    FlipX,
+    FlipY,
+    Transpose,
+    AntiTranspose,
+}
+
+impl Symmetry {
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::Rotate90,
+        Symmetry::Rotate180,
+        Symmetry::Rotate270,
+        Symmetry::FlipX,
+        Symmetry::FlipY,
+        Symmetry::Transpose,
+        Symmetry::AntiTranspose,
+    ];
+
+    pub fn apply(&self, x: usize, y: usize) -> (usize, usize) {
+        match self {
+            Symmetry::Identity => (x, y),
+            Symmetry::Rotate90 => (y, 2 - x),
+            Symmetry::Rotate180 => (2 - x, 2 - y),
+            Symmetry::Rotate270 => (2 - y, x),
+            Symmetry::FlipX => (2 - x, y),
+            Symmetry::FlipY => (x, 2 - y),
+            Symmetry::Transpose => (y, x),
+            Symmetry::AntiTranspose => (2 - y, 2 - x),
+        }
+    }
+
+    pub fn inverse(&self) -> Symmetry {
+        match self {
+            Symmetry::Rotate90 => Symmetry::Rotate270,
+            Symmetry::Rotate270 => Symmetry::Rotate90,
+            symmetry => *symmetry,
+        }
+    }
+
+    pub fn apply_to_move(&self, game_move: Move) -> Move {
+        match game_move {
+            Move::PutPiece(x, y, piece_size) => {
+                let (x, y) = self.apply(x, y);
+                Move::PutPiece(x, y, piece_size)
+            }
+            Move::MovePiece(origin_x, origin_y, destination_x, destination_y) => {
+                let (origin_x, origin_y) = self.apply(origin_x, origin_y);
+                let (destination_x, destination_y) = self.apply(destination_x, destination_y);
+                Move::MovePiece(origin_x, origin_y, destination_x, destination_y)
+            }
+        }
+    }
+
+    pub fn transform_squares<T: Clone>(&self, squares: &[[T; 3]; 3]) -> [[T; 3]; 3] {
+        let inverse = self.inverse();
+
+        std::array::from_fn(|x| {
+            std::array::from_fn(|y| {
+                let (origin_x, origin_y) = inverse.apply(x, y);
+                squares[origin_x][origin_y].clone()
+            })
+        })
+    }
+}
+
+fn piece_state_code(piece_state: &PieceState) -> u128 {
+    let color_code = match piece_state.color {
+        Color::Red => 0,
+        Color::Blue => 3,
+    };
+
+    piece_state.size as u128 + color_code + 1
+}
+
+fn square_code(square: &Option<PieceState>) -> u128 {
+    let mut code = 0;
+    let mut piece_state = square.as_ref();
+
+    while let Some(current_piece_state) = piece_state {
+        code = code * 7 + piece_state_code(current_piece_state);
+        piece_state = current_piece_state.nested_piece.as_deref();
+    }
+
+    code
+}
+
+impl BoardState {
+    pub fn transform(&self, symmetry: Symmetry) -> BoardState {
+        BoardState {
+            squares: symmetry.transform_squares(&self.squares),
+        }
+    }
+
+    pub fn symmetry_key(&self, symmetry: Symmetry) -> u128 {
+        let inverse = symmetry.inverse();
+        let mut key = 0;
+
+        for x in 0..3 {
+            for y in 0..3 {
+                let (origin_x, origin_y) = inverse.apply(x, y);
+                key = (key << 9) | square_code(&self.squares[origin_x][origin_y]);
+            }
+        }
+
+        key
+    }
+
+    pub fn canonical_symmetry(&self) -> Symmetry {
+        Symmetry::ALL
+            .into_iter()
+            .min_by_key(|symmetry| self.symmetry_key(*symmetry))
+            .unwrap_or(Symmetry::Identity)
+    }
+
+    pub fn canonical_key(&self) -> u128 {
+        self.symmetry_key(self.canonical_symmetry())
+    }
+
+    pub fn canonical(&self) -> (BoardState, Symmetry) {
+        let symmetry = self.canonical_symmetry();
+        (self.transform(symmetry), symmetry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::board::Board;
+    use crate::model::game_instance::GameInstance;
+    use crate::model::game_move::Move;
+    use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+    use crate::model::symmetry::Symmetry;
+
+    #[test]
+    fn symmetry_inverse_test() {
+        for symmetry in Symmetry::ALL {
+            for x in 0..3 {
+                for y in 0..3 {
+                    let (transformed_x, transformed_y) = symmetry.apply(x, y);
+                    assert_eq!(
+                        symmetry.inverse().apply(transformed_x, transformed_y),
+                        (x, y)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn symmetry_keeps_center_test() {
+        for symmetry in Symmetry::ALL {
+            assert_eq!(symmetry.apply(1, 1), (1, 1));
+        }
+    }
+
+    #[test]
+    fn symmetries_are_distinct_test() {
+        let transformed_corners: Vec<_> = Symmetry::ALL
+            .iter()
+            .map(|symmetry| (symmetry.apply(0, 0), symmetry.apply(0, 1)))
+            .collect();
+
+        for (i, transformed_corner) in transformed_corners.iter().enumerate() {
+            assert!(!transformed_corners[i + 1..].contains(transformed_corner));
+        }
+    }
+
+    #[test]
+    fn apply_to_move_test() {
+        assert_eq!(
+            Symmetry::Rotate90.apply_to_move(Move::PutPiece(0, 0, Big)),
+            Move::PutPiece(0, 2, Big)
+        );
+        assert_eq!(
+            Symmetry::FlipX.apply_to_move(Move::MovePiece(0, 1, 1, 2)),
+            Move::MovePiece(2, 1, 1, 2)
+        );
+    }
+
+    #[test]
+    fn canonical_form_of_equivalent_positions_test() {
+        let moves = [
+            Move::PutPiece(0, 0, Small),
+            Move::PutPiece(0, 1, Medium),
+            Move::PutPiece(0, 1, Big),
+            Move::PutPiece(2, 1, Small),
+        ];
+
+        let mut canonical_keys = Vec::new();
+
+        for symmetry in Symmetry::ALL {
+            let mut game_instance = GameInstance::default();
+
+            for game_move in moves {
+                game_instance
+                    .play(symmetry.apply_to_move(game_move))
+                    .unwrap();
+            }
+
+            let board_state = game_instance.to_game_state().board;
+            let (canonical_board_state, canonical_symmetry) = board_state.canonical();
+
+            assert_eq!(
+                board_state.transform(canonical_symmetry),
+                canonical_board_state
+            );
+            canonical_keys.push(board_state.canonical_key());
+        }
+
+        assert!(canonical_keys.iter().all(|key| *key == canonical_keys[0]));
+    }
+
+    #[test]
+    fn canonical_form_distinguishes_positions_test() {
+        let mut corner = GameInstance::default();
+        corner.play(Move::PutPiece(0, 0, Small)).unwrap();
+
+        let mut edge = GameInstance::default();
+        edge.play(Move::PutPiece(0, 1, Small)).unwrap();
+
+        assert_ne!(
+            corner.to_game_state().board.canonical_key(),
+            edge.to_game_state().board.canonical_key()
+        );
+    }
+
+    #[test]
+    fn board_canonical_matches_board_state_test() {
+        let mut game_instance = GameInstance::default();
+        game_instance.play(Move::PutPiece(2, 1, Small)).unwrap();
+        game_instance.play(Move::PutPiece(2, 1, Big)).unwrap();
+
+        let board: &Board = game_instance.get_board();
+        let (canonical_board, symmetry) = board.canonical();
+
+        assert_eq!(symmetry, board.to_board_state().canonical_symmetry());
+        assert_eq!(
+            canonical_board.to_board_state(),
+            board.to_board_state().canonical().0
+        );
+    }
+
+    #[test]
+    fn mapping_moves_to_canonical_position_test() {
+        let mut game_instance = GameInstance::default();
+        game_instance.play(Move::PutPiece(2, 1, Medium)).unwrap();
+
+        let (_, symmetry) = game_instance.get_board().canonical();
+
+        let mut canonical_game_instance = GameInstance::default();
+        canonical_game_instance
+            .play(symmetry.apply_to_move(Move::PutPiece(2, 1, Medium)))
+            .unwrap();
+
+        game_instance.play(Move::PutPiece(2, 1, Big)).unwrap();
+        canonical_game_instance
+            .play(symmetry.apply_to_move(Move::PutPiece(2, 1, Big)))
+            .unwrap();
+
+        assert_eq!(
+            canonical_game_instance.to_game_state().board,
+            game_instance.to_game_state().board.transform(symmetry)
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use crate::model::game_state::{BoardState, PlayerState};
+use crate::model::piece_size::PieceSize;
+use crate::model::player::Color;
+
+const SEED: u64 = 0x6762_6c74_676f_626c;
+const PIECE_KEY_COUNT: usize = 9 * 2 * 3;
+const RESERVE_KEY_COUNT: usize = 2 * 3 * 3;
+const KEY_COUNT: usize = PIECE_KEY_COUNT + RESERVE_KEY_COUNT + 1;
+
+const fn splitmix64(state: u64) -> (u64, u64) {
+    let state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut value = state;
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    (state, value ^ (value >> 31))
+}
+
+const KEYS: [u64; KEY_COUNT] = {
+    let mut keys = [0; KEY_COUNT];
+    let mut state = SEED;
+    let mut i = 0;
+
+    while i < KEY_COUNT {
+        let (next_state, value) = splitmix64(state);
+        keys[i] = value;
+        state = next_state;
+        i += 1;
+    }
+
+    keys
+};
+
+fn color_index(color: Color) -> usize {
+    match color {
+        Color::Red => 0,
+        Color::Blue => 1,
+    }
+}
+
+fn size_index(piece_size: PieceSize) -> usize {
+    match piece_size {
+        PieceSize::Small => 0,
+        PieceSize::Medium => 1,
+        PieceSize::Big => 2,
+    }
+}
+
+// Les tailles étant strictement croissantes dans une pile, l'ensemble des pièces
+// d'une case suffit à décrire la pile : découvrir une pièce ne change pas sa clé.
+pub fn piece_key(x: usize, y: usize, color: Color, piece_size: PieceSize) -> u64 {
+    KEYS[((x * 3 + y) * 2 + color_index(color)) * 3 + size_index(piece_size)]
+}
+
+pub fn reserve_key(color: Color, piece_size: PieceSize, count: usize) -> u64 {
+    match count {
+        0 => 0,
+        count => {
+            KEYS[PIECE_KEY_COUNT + (color_index(color) * 3 + size_index(piece_size)) * 3 + count
+                - 1]
+        }
+    }
+}
+
+pub fn side_to_move_key() -> u64 {
+    KEYS[KEY_COUNT - 1]
+}
+
+pub fn compute_hash(
+    board_state: &BoardState,
+    player_states: &[PlayerState],
+    current_color: Color,
+) -> u64 {
+    let mut hash = 0;
+
+    for x in 0..3 {
+        for y in 0..3 {
+            let mut piece_state = board_state.squares[x][y].as_ref();
+
+            while let Some(current_piece_state) = piece_state {
+                hash ^= piece_key(x, y, current_piece_state.color, current_piece_state.size);
+                piece_state = current_piece_state.nested_piece.as_deref();
+            }
+        }
+    }
+
+    for player_state in player_states {
+        for piece_size in [PieceSize::Small, PieceSize::Medium, PieceSize::Big] {
+            let count = player_state
+                .pieces
+                .iter()
+                .filter(|reserve_piece_size| **reserve_piece_size == piece_size)
+                .count();
+            hash ^= reserve_key(player_state.color, piece_size, count);
+        }
+    }
+
+    if current_color == Color::Blue {
+        hash ^= side_to_move_key();
+    }
+
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::piece_size::PieceSize::{Big, Small};
+    use crate::model::player::Color::{Blue, Red};
+    use crate::model::zobrist::{piece_key, reserve_key, side_to_move_key, KEYS};
+
+    #[test]
+    fn keys_are_distinct_test() {
+        for (i, key) in KEYS.iter().enumerate() {
+            assert_ne!(*key, 0);
+            assert!(!KEYS[i + 1..].contains(key));
+        }
+    }
+
+    #[test]
+    fn key_lookup_test() {
+        assert_ne!(piece_key(0, 0, Red, Small), piece_key(0, 0, Blue, Small));
+        assert_ne!(piece_key(2, 2, Blue, Big), side_to_move_key());
+        assert_eq!(reserve_key(Red, Big, 0), 0);
+        assert_ne!(reserve_key(Red, Big, 1), reserve_key(Red, Big, 2));
+    }
+}
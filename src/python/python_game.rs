@@ -0,0 +1,194 @@
+use crate::ihm::json;
+use crate::model::game_error::GameError;
+use crate::model::game_instance::GameInstance;
+use crate::model::game_move::Move;
+use crate::model::game_state::GameState;
+use crate::model::player::Color;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+pub const OBSERVATION_SIZE: usize = 2 * 3 * 9 + 2 * 3 + 1;
+
+#[derive(FromPyObject, IntoPyObject)]
+enum PythonMove {
+    PutPiece(usize, usize, String),
+    MovePiece(usize, usize, usize, usize),
+}
+
+impl PythonMove {
+    fn to_move(&self) -> PyResult<Move> {
+        let coordinates_are_valid = match self {
+            PythonMove::PutPiece(x, y, _) => [x, y].iter().all(|&&coordinate| coordinate < 3),
+            PythonMove::MovePiece(origin_x, origin_y, destination_x, destination_y) => {
+                [origin_x, origin_y, destination_x, destination_y]
+                    .iter()
+                    .all(|&&coordinate| coordinate < 3)
+            }
+        };
+        if !coordinates_are_valid {
+            return Err(PyValueError::new_err("Case hors du plateau"));
+        }
+
+        match self {
+            PythonMove::PutPiece(x, y, size) => json::piece_size_from_name(size)
+                .map(|piece_size| Move::PutPiece(*x, *y, piece_size))
+                .ok_or_else(|| {
+                    PyValueError::new_err(format!("Taille de pièce inconnue : {}", size))
+                }),
+            PythonMove::MovePiece(origin_x, origin_y, destination_x, destination_y) => Ok(
+                Move::MovePiece(*origin_x, *origin_y, *destination_x, *destination_y),
+            ),
+        }
+    }
+}
+
+impl From<Move> for PythonMove {
+    fn from(game_move: Move) -> Self {
+        match game_move {
+            Move::PutPiece(x, y, piece_size) => {
+                PythonMove::PutPiece(x, y, json::piece_size_name(piece_size).to_string())
+            }
+            Move::MovePiece(origin_x, origin_y, destination_x, destination_y) => {
+                PythonMove::MovePiece(origin_x, origin_y, destination_x, destination_y)
+            }
+        }
+    }
+}
+
+fn color_name(color: Color) -> &'static str {
+    match color {
+        Color::Red => "red",
+        Color::Blue => "blue",
+    }
+}
+
+fn to_python_error(game_error: GameError) -> PyErr {
+    PyValueError::new_err(game_error.to_string())
+}
+
+/// Plans binaires couleur × taille × case (pièces recouvertes comprises), puis les
+/// réserves couleur × taille, puis la couleur qui doit jouer (0 rouge, 1 bleu).
+pub fn observation(game_state: &GameState) -> Vec<u32> {
+    let mut observation = vec![0; OBSERVATION_SIZE];
+
+    for (square_index, square) in game_state.board.squares.iter().flatten().enumerate() {
+        let mut piece_state = square.as_ref();
+        while let Some(current_piece_state) = piece_state {
+            let plane = (current_piece_state.color == Color::Blue) as usize * 3
+                + current_piece_state.size as usize;
+            observation[plane * 9 + square_index] = 1;
+            piece_state = current_piece_state.nested_piece.as_deref();
+        }
+    }
+
+    for player_state in &game_state.players {
+        for piece_size in &player_state.pieces {
+            observation
+                [54 + (player_state.color == Color::Blue) as usize * 3 + *piece_size as usize] += 1;
+        }
+    }
+
+    observation[OBSERVATION_SIZE - 1] = (game_state.current_color == Color::Blue) as u32;
+    observation
+}
+
+#[pyclass(name = "GameInstance")]
+#[derive(Clone, Default)]
+pub struct PythonGameInstance {
+    game_instance: GameInstance,
+}
+
+#[pymethods]
+impl PythonGameInstance {
+    #[new]
+    #[pyo3(signature = (position=None))]
+    fn new(position: Option<&str>) -> PyResult<Self> {
+        match position {
+            Some(position) => position
+                .parse()
+                .map(|game_instance| PythonGameInstance { game_instance })
+                .map_err(to_python_error),
+            None => Ok(PythonGameInstance::default()),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.game_instance = GameInstance::default();
+    }
+
+    fn legal_moves(&self) -> Vec<PythonMove> {
+        self.game_instance
+            .legal_moves()
+            .into_iter()
+            .map(PythonMove::from)
+            .collect()
+    }
+
+    fn apply_move(&mut self, game_move: PythonMove) -> PyResult<()> {
+        self.game_instance
+            .play(game_move.to_move()?)
+            .map_err(to_python_error)
+    }
+
+    fn clone(&self) -> Self {
+        Clone::clone(self)
+    }
+
+    fn __copy__(&self) -> Self {
+        Clone::clone(self)
+    }
+
+    fn winner(&self) -> Option<&'static str> {
+        self.game_instance.get_winner().map(color_name)
+    }
+
+    fn is_over(&self) -> bool {
+        self.game_instance.get_winner().is_some()
+    }
+
+    fn current_color(&self) -> &'static str {
+        color_name(self.game_instance.get_current_color())
+    }
+
+    fn observation(&self) -> Vec<u32> {
+        observation(&self.game_instance.to_game_state())
+    }
+
+    fn position(&self) -> String {
+        self.game_instance.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("GameInstance(\"{}\")", self.game_instance)
+    }
+}
+
+#[pymodule]
+fn gobblets_gobblers(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PythonGameInstance>()?;
+    module.add("OBSERVATION_SIZE", OBSERVATION_SIZE)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::game_instance::GameInstance;
+    use crate::model::piece_size::PieceSize::{Big, Small};
+    use crate::python::python_game::{observation, OBSERVATION_SIZE};
+
+    #[test]
+    fn observation_test() {
+        let mut game_instance = GameInstance::default();
+        game_instance.put_piece(0, 1, Small).unwrap();
+        game_instance.put_piece(0, 1, Big).unwrap();
+
+        let observation = observation(&game_instance.to_game_state());
+
+        assert_eq!(observation.len(), OBSERVATION_SIZE);
+        assert_eq!(observation[1], 1);
+        assert_eq!(observation[5 * 9 + 1], 1);
+        assert_eq!(observation.iter().take(54).sum::<u32>(), 2);
+        assert_eq!(observation[54..60], [1, 2, 2, 2, 2, 1]);
+        assert_eq!(observation[60], 0);
+    }
+}
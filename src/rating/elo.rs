@@ -0,0 +1,44 @@
+pub const INITIAL_RATING: f64 = 1500.0;
+pub const K_FACTOR: f64 = 32.0;
+
+pub fn expected_score(rating: f64, opponent_rating: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((opponent_rating - rating) / 400.0))
+}
+
+pub fn update_ratings(rating: f64, opponent_rating: f64, score: f64) -> (f64, f64) {
+    let rating_change = K_FACTOR * (score - expected_score(rating, opponent_rating));
+    (rating + rating_change, opponent_rating - rating_change)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rating::elo::{expected_score, update_ratings, INITIAL_RATING, K_FACTOR};
+
+    #[test]
+    fn expected_score_test() {
+        assert_eq!(expected_score(INITIAL_RATING, INITIAL_RATING), 0.5);
+        assert!((expected_score(1900.0, 1500.0) - 10.0 / 11.0).abs() < 1e-9);
+        assert!(
+            (expected_score(1700.0, 1500.0) + expected_score(1500.0, 1700.0) - 1.0).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn update_ratings_test() {
+        assert_eq!(
+            update_ratings(INITIAL_RATING, INITIAL_RATING, 1.0),
+            (
+                INITIAL_RATING + K_FACTOR / 2.0,
+                INITIAL_RATING - K_FACTOR / 2.0
+            )
+        );
+        assert_eq!(
+            update_ratings(INITIAL_RATING, INITIAL_RATING, 0.5),
+            (INITIAL_RATING, INITIAL_RATING)
+        );
+
+        let (rating, opponent_rating) = update_ratings(1900.0, 1500.0, 0.0);
+        assert!((rating - (1900.0 - K_FACTOR * 10.0 / 11.0)).abs() < 1e-9);
+        assert_eq!(rating + opponent_rating, 3400.0);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod elo;
+pub mod rating_listener;
+pub mod rating_store;
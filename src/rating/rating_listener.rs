@@ -0,0 +1,134 @@
+use crate::model::game_event::GameEvent;
+use crate::model::listener::Listener;
+use crate::model::player::Color;
+use crate::rating::rating_store::RatingStore;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+struct RatingListenerState {
+    rating_store: RatingStore,
+    game_in_progress: bool,
+}
+
+pub struct RatingListener {
+    path: PathBuf,
+    red_player: String,
+    blue_player: String,
+    state: Mutex<RatingListenerState>,
+}
+
+impl RatingListener {
+    pub fn new(
+        path: PathBuf,
+        rating_store: RatingStore,
+        red_player: &str,
+        blue_player: &str,
+    ) -> RatingListener {
+        RatingListener {
+            path,
+            red_player: red_player.to_string(),
+            blue_player: blue_player.to_string(),
+            state: Mutex::new(RatingListenerState {
+                rating_store,
+                game_in_progress: false,
+            }),
+        }
+    }
+
+    pub fn get_rating_store(&self) -> RatingStore {
+        self.state.lock().unwrap().rating_store.clone()
+    }
+
+    fn record_game(&self, state: &mut RatingListenerState, winner: Option<Color>) {
+        state.game_in_progress = false;
+        state
+            .rating_store
+            .record_game(&self.red_player, &self.blue_player, winner);
+
+        if let Err(error) = state.rating_store.save(&self.path) {
+            eprintln!("Impossible de sauvegarder le classement : {}", error);
+        }
+    }
+}
+
+impl Listener for RatingListener {
+    fn notify(&self, game_event: GameEvent) {
+        let mut state = self.state.lock().unwrap();
+
+        match game_event {
+            GameEvent::NewGameEvent(game_state) => {
+                state.game_in_progress = game_state.winner_color.is_none() && !game_state.draw
+            }
+            GameEvent::MoveAppliedEvent(applied_move)
+                if state.game_in_progress
+                    && (applied_move.winner_color.is_some() || applied_move.draw) =>
+            {
+                self.record_game(&mut state, applied_move.winner_color)
+            }
+            GameEvent::GameTimeoutEvent(loser) | GameEvent::GameForfeitEvent(loser)
+                if state.game_in_progress =>
+            {
+                self.record_game(&mut state, Some(loser.opponent()))
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::game::Game;
+    use crate::model::game_command::GameCommand::{NewGameCommand, PutPieceCommand};
+    use crate::model::game_event::GameEvent::GameTimeoutEvent;
+    use crate::model::listener::Listener;
+    use crate::model::piece_size::PieceSize::{Big, Medium, Small};
+    use crate::model::player::Color::{Blue, Red};
+    use crate::rating::rating_listener::RatingListener;
+    use crate::rating::rating_store::RatingStore;
+    use std::fs;
+    use std::sync::Arc;
+
+    #[test]
+    fn record_finished_games_test() {
+        let path = std::env::temp_dir().join(format!(
+            "gobblets_gobblers_rating_listener_test_{}.txt",
+            std::process::id()
+        ));
+        let rating_listener = Arc::new(RatingListener::new(
+            path.clone(),
+            RatingStore::default(),
+            "alice",
+            "minimax",
+        ));
+        let mut game = Game::default();
+        game.subscribe(Arc::clone(&rating_listener) as Arc<dyn Listener>);
+
+        game.execute(NewGameCommand);
+        for (x, y, size) in [
+            (0, 0, Big),
+            (1, 0, Big),
+            (0, 1, Medium),
+            (1, 1, Medium),
+            (0, 2, Small),
+        ] {
+            game.execute(PutPieceCommand(x, y, size));
+        }
+        game.execute(PutPieceCommand(2, 2, Small));
+
+        let rating_store = rating_listener.get_rating_store();
+        assert_eq!(rating_store.get_results().len(), 1);
+        assert_eq!(rating_store.get_results()[0].winner, Some(Red));
+        assert_eq!(rating_store.get_player("alice").unwrap().wins, 1);
+
+        rating_listener.notify(GameTimeoutEvent(Red));
+        game.execute(NewGameCommand);
+        rating_listener.notify(GameTimeoutEvent(Red));
+
+        let saved_rating_store = RatingStore::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(saved_rating_store.get_results().len(), 2);
+        assert_eq!(saved_rating_store.get_results()[1].winner, Some(Blue));
+        assert_eq!(saved_rating_store.get_player("minimax").unwrap().losses, 1);
+    }
+}
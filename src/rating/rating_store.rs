@@ -0,0 +1,315 @@
+use crate::model::player::Color;
+use crate::rating::elo::{update_ratings, INITIAL_RATING};
+use std::fmt::{Display, Formatter};
+use std::io::ErrorKind;
+use std::path::Path;
+use std::{fmt, fs, io};
+
+const FILE_HEADER: &str = "gobblets-gobblers ratings v1";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerRating {
+    pub name: String,
+    pub rating: f64,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+}
+
+impl PlayerRating {
+    fn new(name: &str) -> PlayerRating {
+        PlayerRating {
+            name: name.to_string(),
+            rating: INITIAL_RATING,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+        }
+    }
+
+    pub fn games_played(&self) -> u32 {
+        self.wins + self.draws + self.losses
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameResult {
+    pub red_player: String,
+    pub blue_player: String,
+    pub winner: Option<Color>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RatingStore {
+    players: Vec<PlayerRating>,
+    results: Vec<GameResult>,
+}
+
+fn result_to_string(winner: Option<Color>) -> &'static str {
+    match winner {
+        Some(Color::Red) => "rouge",
+        Some(Color::Blue) => "bleu",
+        None => "nul",
+    }
+}
+
+impl RatingStore {
+    pub fn get_player(&self, name: &str) -> Option<&PlayerRating> {
+        self.players.iter().find(|player| player.name == name)
+    }
+
+    pub fn get_results(&self) -> &[GameResult] {
+        &self.results
+    }
+
+    fn player_index(&mut self, name: &str) -> usize {
+        match self.players.iter().position(|player| player.name == name) {
+            Some(index) => index,
+            None => {
+                self.players.push(PlayerRating::new(name));
+                self.players.len() - 1
+            }
+        }
+    }
+
+    pub fn record_game(&mut self, red_player: &str, blue_player: &str, winner: Option<Color>) {
+        let red_index = self.player_index(red_player);
+        let blue_index = self.player_index(blue_player);
+
+        let red_score = match winner {
+            Some(Color::Red) => 1.0,
+            Some(Color::Blue) => 0.0,
+            None => 0.5,
+        };
+
+        if red_index != blue_index {
+            let (red_rating, blue_rating) = update_ratings(
+                self.players[red_index].rating,
+                self.players[blue_index].rating,
+                red_score,
+            );
+            self.players[red_index].rating = red_rating;
+            self.players[blue_index].rating = blue_rating;
+        }
+
+        for (index, color) in [(red_index, Color::Red), (blue_index, Color::Blue)] {
+            let player = &mut self.players[index];
+            match winner {
+                Some(winner) if winner == color => player.wins += 1,
+                Some(_) => player.losses += 1,
+                None => player.draws += 1,
+            }
+        }
+
+        self.results.push(GameResult {
+            red_player: red_player.to_string(),
+            blue_player: blue_player.to_string(),
+            winner,
+        });
+    }
+
+    pub fn leaderboard(&self) -> Vec<&PlayerRating> {
+        let mut players: Vec<&PlayerRating> = self.players.iter().collect();
+        players.sort_by(|first, second| {
+            second
+                .rating
+                .total_cmp(&first.rating)
+                .then_with(|| first.name.cmp(&second.name))
+        });
+        players
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut content = String::from(FILE_HEADER);
+        content.push('\n');
+
+        for player in &self.players {
+            content.push_str(&format!(
+                "player\t{}\t{}\t{}\t{}\t{}\n",
+                player.name, player.rating, player.wins, player.draws, player.losses
+            ));
+        }
+
+        for result in &self.results {
+            content.push_str(&format!(
+                "game\t{}\t{}\t{}\n",
+                result.red_player,
+                result.blue_player,
+                result_to_string(result.winner)
+            ));
+        }
+
+        fs::write(path, content)
+    }
+
+    pub fn load(path: &Path) -> io::Result<RatingStore> {
+        let content = fs::read_to_string(path)?;
+        let mut lines = content.lines();
+
+        if lines.next() != Some(FILE_HEADER) {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "En-tête du fichier de classement invalide",
+            ));
+        }
+
+        let mut rating_store = RatingStore::default();
+        for line in lines.filter(|line| !line.trim().is_empty()) {
+            let invalid_line =
+                || io::Error::new(ErrorKind::InvalidData, format!("Ligne invalide : {}", line));
+            let fields: Vec<&str> = line.split('\t').collect();
+
+            match fields[..] {
+                ["player", name, rating, wins, draws, losses] => {
+                    rating_store.players.push(PlayerRating {
+                        name: name.to_string(),
+                        rating: rating.parse().map_err(|_| invalid_line())?,
+                        wins: wins.parse().map_err(|_| invalid_line())?,
+                        draws: draws.parse().map_err(|_| invalid_line())?,
+                        losses: losses.parse().map_err(|_| invalid_line())?,
+                    })
+                }
+                ["game", red_player, blue_player, winner] => {
+                    rating_store.results.push(GameResult {
+                        red_player: red_player.to_string(),
+                        blue_player: blue_player.to_string(),
+                        winner: match winner {
+                            "rouge" => Some(Color::Red),
+                            "bleu" => Some(Color::Blue),
+                            "nul" => None,
+                            _ => return Err(invalid_line()),
+                        },
+                    })
+                }
+                _ => return Err(invalid_line()),
+            }
+        }
+
+        Ok(rating_store)
+    }
+}
+
+impl Display for RatingStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<5} {:<20} {:>6} {:>8} {:>4} {:>4} {:>4}",
+            "Rang", "Joueur", "Elo", "Parties", "V", "N", "D"
+        )?;
+
+        for (rank, player) in self.leaderboard().iter().enumerate() {
+            writeln!(
+                f,
+                "{:<5} {:<20} {:>6.0} {:>8} {:>4} {:>4} {:>4}",
+                rank + 1,
+                player.name,
+                player.rating,
+                player.games_played(),
+                player.wins,
+                player.draws,
+                player.losses
+            )?;
+        }
+
+        write!(f, "{} partie(s) enregistrée(s)", self.results.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::player::Color::{Blue, Red};
+    use crate::rating::elo::INITIAL_RATING;
+    use crate::rating::rating_store::RatingStore;
+    use std::fs;
+
+    #[test]
+    fn record_game_test() {
+        let mut rating_store = RatingStore::default();
+
+        rating_store.record_game("alice", "minimax", Some(Blue));
+        rating_store.record_game("minimax", "alice", None);
+
+        let alice = rating_store.get_player("alice").unwrap();
+        let minimax = rating_store.get_player("minimax").unwrap();
+
+        assert!(minimax.rating > INITIAL_RATING);
+        assert!(alice.rating < INITIAL_RATING);
+        assert!((alice.rating + minimax.rating - 2.0 * INITIAL_RATING).abs() < 1e-9);
+        assert_eq!((minimax.wins, minimax.draws, minimax.losses), (1, 1, 0));
+        assert_eq!((alice.wins, alice.draws, alice.losses), (0, 1, 1));
+        assert_eq!(rating_store.get_results().len(), 2);
+        assert_eq!(rating_store.get_results()[0].winner, Some(Blue));
+    }
+
+    #[test]
+    fn self_play_keeps_rating_test() {
+        let mut rating_store = RatingStore::default();
+
+        rating_store.record_game("mcts", "mcts", Some(Red));
+
+        let mcts = rating_store.get_player("mcts").unwrap();
+        assert_eq!(mcts.rating, INITIAL_RATING);
+        assert_eq!((mcts.wins, mcts.losses), (1, 1));
+    }
+
+    #[test]
+    fn leaderboard_test() {
+        let mut rating_store = RatingStore::default();
+
+        rating_store.record_game("random", "minimax", Some(Blue));
+        rating_store.record_game("mcts", "random", Some(Red));
+        rating_store.record_game("minimax", "mcts", Some(Red));
+
+        let names: Vec<&str> = rating_store
+            .leaderboard()
+            .iter()
+            .map(|player| player.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["minimax", "mcts", "random"]);
+
+        let leaderboard = rating_store.to_string();
+        assert!(leaderboard
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with("1     minimax"));
+        assert!(leaderboard.ends_with("3 partie(s) enregistrée(s)"));
+    }
+
+    #[test]
+    fn save_and_load_test() {
+        let mut rating_store = RatingStore::default();
+        rating_store.record_game("alice", "bob l'éponge", Some(Red));
+        rating_store.record_game("bob l'éponge", "alice", None);
+
+        let path = std::env::temp_dir().join(format!(
+            "gobblets_gobblers_ratings_test_{}.txt",
+            std::process::id()
+        ));
+        rating_store.save(&path).unwrap();
+        let loaded_rating_store = RatingStore::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded_rating_store.players, rating_store.players);
+        assert_eq!(loaded_rating_store.results, rating_store.results);
+    }
+
+    #[test]
+    fn load_invalid_file_test() {
+        let path = std::env::temp_dir().join(format!(
+            "gobblets_gobblers_invalid_ratings_test_{}.txt",
+            std::process::id()
+        ));
+
+        for content in [
+            "ratings\n",
+            "gobblets-gobblers ratings v1\nplayer\talice\tabc\t0\t0\t0\n",
+            "gobblets-gobblers ratings v1\ngame\talice\tbob\tvert\n",
+        ] {
+            fs::write(&path, content).unwrap();
+            assert!(RatingStore::load(&path).is_err());
+        }
+
+        fs::remove_file(&path).unwrap();
+    }
+}
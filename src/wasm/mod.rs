@@ -0,0 +1 @@
+pub mod wasm_game;
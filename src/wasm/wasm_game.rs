@@ -0,0 +1,114 @@
+use crate::ihm::json;
+use crate::model::game_error::GameError;
+use crate::model::game_instance::GameInstance;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Default)]
+pub struct WasmGame {
+    game_instance: GameInstance,
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl WasmGame {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(constructor))]
+    pub fn new() -> WasmGame {
+        WasmGame::default()
+    }
+
+    pub fn from_position(position: &str) -> Result<WasmGame, String> {
+        position
+            .parse()
+            .map(|game_instance| WasmGame { game_instance })
+            .map_err(|game_error| json::game_error_to_json(&game_error))
+    }
+
+    pub fn state(&self) -> String {
+        json::game_state_to_json(&self.game_instance.to_game_state())
+    }
+
+    pub fn position(&self) -> String {
+        self.game_instance.to_string()
+    }
+
+    pub fn legal_moves(&self) -> String {
+        let moves: Vec<String> = self
+            .game_instance
+            .legal_moves()
+            .iter()
+            .map(json::move_to_json)
+            .collect();
+
+        format!("[{}]", moves.join(","))
+    }
+
+    pub fn put_piece(&mut self, x: usize, y: usize, size: &str) -> Result<String, String> {
+        let piece_size = json::piece_size_from_name(size).ok_or_else(|| {
+            json::error_to_json(
+                "BadRequest",
+                &format!("Taille de pièce inconnue : {}", size),
+            )
+        })?;
+        let result = self.game_instance.put_piece(x, y, piece_size);
+        self.to_response(result)
+    }
+
+    pub fn move_piece(
+        &mut self,
+        origin_x: usize,
+        origin_y: usize,
+        destination_x: usize,
+        destination_y: usize,
+    ) -> Result<String, String> {
+        let result =
+            self.game_instance
+                .move_piece(origin_x, origin_y, destination_x, destination_y);
+        self.to_response(result)
+    }
+
+    fn to_response(&self, result: Result<(), GameError>) -> Result<String, String> {
+        result
+            .map(|_| self.state())
+            .map_err(|game_error| json::game_error_to_json(&game_error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wasm::wasm_game::WasmGame;
+
+    #[test]
+    fn new_game_test() {
+        let wasm_game = WasmGame::new();
+
+        assert!(wasm_game.state().contains("\"current_color\":\"red\""));
+        assert_eq!(wasm_game.position(), "-,-,-/-,-,-/-,-,- SSMMBB ssmmbb r 0");
+        assert!(wasm_game
+            .legal_moves()
+            .starts_with("[{\"type\":\"put\",\"x\":0,\"y\":0,"));
+        assert_eq!(
+            wasm_game.legal_moves().matches("\"type\":\"put\"").count(),
+            27
+        );
+    }
+
+    #[test]
+    fn play_test() {
+        let mut wasm_game = WasmGame::new();
+
+        assert!(wasm_game
+            .put_piece(1, 1, "big")
+            .unwrap()
+            .contains("\"current_color\":\"blue\""));
+        assert!(wasm_game
+            .move_piece(1, 1, 0, 0)
+            .unwrap_err()
+            .contains("\"error\":\"NotYourPiece\""));
+        assert!(wasm_game
+            .put_piece(0, 0, "huge")
+            .unwrap_err()
+            .contains("Taille de pièce inconnue"));
+        assert!(WasmGame::from_position("invalide").is_err());
+    }
+}
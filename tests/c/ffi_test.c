@@ -0,0 +1,54 @@
+#include <stdio.h>
+
+#include "gobblets_gobblers.h"
+
+#define CHECK(condition)                                                 \
+    do {                                                                 \
+        if (!(condition)) {                                              \
+            fprintf(stderr, "%s:%d: échec de %s\n", __FILE__, __LINE__, \
+                    #condition);                                         \
+            return 1;                                                    \
+        }                                                                \
+    } while (0)
+
+int main(void) {
+    GobbletsGame *game = gobblets_game_new();
+    GobbletsState state;
+
+    CHECK(game != NULL);
+    CHECK(gobblets_game_put_piece(game, 0, 0, GOBBLETS_PIECE_SIZE_BIG) == GOBBLETS_ERROR_OK);
+    CHECK(gobblets_game_put_piece(game, 0, 0, GOBBLETS_PIECE_SIZE_SMALL) ==
+          GOBBLETS_ERROR_CANNOT_PUT_PIECE_HERE);
+    CHECK(gobblets_game_put_piece(game, 1, 0, GOBBLETS_PIECE_SIZE_SMALL) == GOBBLETS_ERROR_OK);
+    CHECK(gobblets_game_put_piece(game, 1, 1, GOBBLETS_PIECE_SIZE_BIG) == GOBBLETS_ERROR_OK);
+    CHECK(gobblets_game_move_piece(game, 1, 0, 2, 0) == GOBBLETS_ERROR_OK);
+    CHECK(gobblets_game_move_piece(game, 0, 0, 2, 0) == GOBBLETS_ERROR_OK);
+    CHECK(gobblets_game_move_piece(game, 2, 0, 3, 0) == GOBBLETS_ERROR_INVALID_ARGUMENT);
+
+    CHECK(gobblets_game_get_state(game, &state) == GOBBLETS_ERROR_OK);
+    CHECK(state.turn == 5);
+    CHECK(state.current_color == GOBBLETS_COLOR_BLUE);
+    CHECK(state.outcome == GOBBLETS_OUTCOME_IN_PROGRESS);
+    CHECK(state.squares[0][0].height == 0);
+    CHECK(state.squares[2][0].height == 2);
+    CHECK(state.squares[2][0].pieces[0].color == GOBBLETS_COLOR_BLUE);
+    CHECK(state.squares[2][0].pieces[1].size == GOBBLETS_PIECE_SIZE_BIG);
+    CHECK(state.players[0].big_pieces == 0);
+    CHECK(state.players[1].small_pieces == 1);
+    CHECK(state.players[1].remaining_time_ms == -1);
+
+    gobblets_game_free(game);
+
+    game = gobblets_game_from_position("B,-,-/s,B,-/-,-,M SSM smmbb b 4");
+    CHECK(game != NULL);
+    CHECK(gobblets_game_get_state(game, &state) == GOBBLETS_ERROR_OK);
+    CHECK(state.outcome == GOBBLETS_OUTCOME_RED_WINS);
+    CHECK(gobblets_game_put_piece(game, 0, 1, GOBBLETS_PIECE_SIZE_SMALL) ==
+          GOBBLETS_ERROR_GAME_IS_OVER);
+    gobblets_game_free(game);
+
+    CHECK(gobblets_game_from_position("invalide") == NULL);
+
+    printf("ok\n");
+    return 0;
+}
@@ -0,0 +1,44 @@
+#![cfg(all(feature = "ai", feature = "console", feature = "networking"))]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+fn run_console(input: &str) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_gobblets-gobblers"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+
+    let started_at = Instant::now();
+    while started_at.elapsed() < Duration::from_secs(5) {
+        if let Some(status) = child.try_wait().unwrap() {
+            assert!(status.success());
+            return;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    panic!("La console ne s'est pas arrêtée");
+}
+
+#[test]
+fn console_exits_after_exit_command_test() {
+    run_console("1\n2 1 1 3\n4\n");
+}
+
+#[test]
+fn console_exits_at_end_of_input_test() {
+    run_console("1\n");
+}
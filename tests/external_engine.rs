@@ -0,0 +1,35 @@
+#![cfg(feature = "ai")]
+
+use gobblets_gobblers::ai::external_engine::ExternalEngine;
+use gobblets_gobblers::ai::minimax::Minimax;
+use gobblets_gobblers::ihm::channel_listener::ChannelListener;
+use gobblets_gobblers::model::game::Game;
+use gobblets_gobblers::model::game_command::GameCommand::NewGameCommand;
+use gobblets_gobblers::model::game_event::GameEvent;
+use gobblets_gobblers::model::player::Color::{Blue, Red};
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+#[test]
+fn engine_binary_plays_full_game_test() {
+    let engine = ExternalEngine::start(
+        env!("CARGO_BIN_EXE_gobblets-engine"),
+        &[],
+        Duration::from_millis(50),
+    )
+    .unwrap();
+
+    let (tx_event, rx_event) = mpsc::channel();
+    let mut game = Game::default();
+    game.set_repetition_draw(true);
+    game.subscribe(Arc::new(ChannelListener::new(tx_event)));
+    game.set_bot(Red, Box::new(engine));
+    game.set_bot(Blue, Box::new(Minimax::new(1)));
+
+    game.execute(NewGameCommand);
+
+    assert!(game.get_winner().is_some() || game.is_draw());
+    assert!(rx_event
+        .try_iter()
+        .all(|game_event| !matches!(game_event, GameEvent::GameForfeitEvent(_))));
+}
@@ -0,0 +1,47 @@
+#![cfg(all(unix, feature = "ffi"))]
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn c_program_test() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let library_dir = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("ffi_test");
+
+    let status = Command::new(env::var("CC").unwrap_or_else(|_| String::from("cc")))
+        .arg(manifest_dir.join("tests/c/ffi_test.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(&library_dir)
+        .arg(format!("-Wl,-rpath,{}", library_dir.display()))
+        .arg("-lgobblets_gobblers")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .expect("Compilateur C introuvable");
+    assert!(status.success());
+
+    let output = Command::new(&program).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "ok\n");
+}
+
+#[test]
+fn header_is_up_to_date_test() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let generated_header = Path::new(env!("OUT_DIR")).join("gobblets_gobblers.h");
+
+    assert_eq!(
+        fs::read_to_string(manifest_dir.join("include/gobblets_gobblers.h")).unwrap(),
+        fs::read_to_string(generated_header).unwrap(),
+        "En-tête C obsolète : relancer la compilation avec GOBBLETS_UPDATE_HEADER=1"
+    );
+}
@@ -0,0 +1,59 @@
+import copy
+import unittest
+
+from gobblets_gobblers import OBSERVATION_SIZE, GameInstance
+
+
+class GameInstanceTest(unittest.TestCase):
+    def test_new_game(self):
+        game = GameInstance()
+
+        self.assertEqual(game.position(), "-,-,-/-,-,-/-,-,- SSMMBB ssmmbb r 0")
+        self.assertEqual(game.current_color(), "red")
+        self.assertEqual(len(game.legal_moves()), 27)
+        self.assertIn((0, 0, "small"), game.legal_moves())
+        self.assertIsNone(game.winner())
+
+    def test_apply_move(self):
+        game = GameInstance()
+        game.apply_move((1, 1, "big"))
+        game.apply_move((0, 0, "small"))
+
+        self.assertIn((1, 1, 2, 2), game.legal_moves())
+        with self.assertRaises(ValueError):
+            game.apply_move((0, 0, 2, 2))
+        with self.assertRaises(ValueError):
+            game.apply_move((3, 0, "small"))
+        with self.assertRaises(ValueError):
+            game.apply_move((0, 1, "huge"))
+
+    def test_clone_and_reset(self):
+        game = GameInstance()
+        game.apply_move((1, 1, "big"))
+        clone = game.clone()
+        clone.apply_move((0, 0, "small"))
+
+        self.assertEqual(game.current_color(), "blue")
+        self.assertEqual(copy.copy(clone).position(), clone.position())
+        game.reset()
+        self.assertEqual(game.position(), GameInstance().position())
+
+    def test_winner(self):
+        game = GameInstance("B,-,-/s,B,-/-,-,M SSM smmbb b 4")
+
+        self.assertEqual(game.winner(), "red")
+        self.assertTrue(game.is_over())
+        self.assertEqual(game.legal_moves(), [])
+        with self.assertRaises(ValueError):
+            GameInstance("invalide")
+
+    def test_observation(self):
+        observation = GameInstance().observation()
+
+        self.assertEqual(len(observation), OBSERVATION_SIZE)
+        self.assertEqual(observation[54:60], [2, 2, 2, 2, 2, 2])
+        self.assertEqual(observation[-1], 0)
+
+
+if __name__ == "__main__":
+    unittest.main()